@@ -1,2 +0,0 @@
-#!/bin/bash
-curl -b session=$(<.session) -o ./input/day/$1/input https://adventofcode.com/2023/day/$1/input
\ No newline at end of file
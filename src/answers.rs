@@ -0,0 +1,107 @@
+use std::{
+    fs::{create_dir_all, read_to_string, write},
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Result;
+
+use crate::{
+    io,
+    solution::{Part, Runnable},
+};
+
+/// Where the known answer for a day and part is kept.
+pub fn path(day: usize, part: Part) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("answers/day/{}/part{}", day, part))
+}
+
+pub fn expected(day: usize, part: Part) -> Result<Option<String>> {
+    match read_to_string(path(day, part)) {
+        Ok(s) => Ok(Some(s.trim().to_string())),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+pub fn record(day: usize, part: Part, answer: &str) -> Result<()> {
+    let path = path(day, part);
+    if let Some(dir) = path.parent() {
+        create_dir_all(dir)?;
+    }
+
+    Ok(write(path, format!("{}\n", answer))?)
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Check {
+    pub day: usize,
+    pub part: Part,
+    pub expected: String,
+    pub actual: String,
+}
+
+impl Check {
+    pub fn passed(&self) -> bool {
+        self.expected == self.actual
+    }
+}
+
+/// Runs every part of `day` that has a known answer against the real input.
+///
+/// Parts without a stored answer are skipped, so unsolved days don't panic.
+pub fn verify(day: &dyn Runnable) -> Result<Vec<Check>> {
+    let mut parts = Vec::new();
+    let mut expectations = Vec::new();
+    for part in Part::values() {
+        if let Some(expected) = expected(day.day(), part)? {
+            parts.push(part);
+            expectations.push(expected);
+        }
+    }
+
+    if parts.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let input = io::for_day(day.day())?;
+    let actuals = day.run(&input, &parts)?;
+
+    Ok(parts
+        .into_iter()
+        .zip(expectations)
+        .zip(actuals)
+        .map(|((part, expected), actual)| Check {
+            day: day.day(),
+            part,
+            expected,
+            actual,
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expected() -> Result<()> {
+        assert_eq!(expected(1, Part::One)?, Some("53334".to_string()));
+        // there is never a second puzzle on christmas day
+        assert_eq!(expected(25, Part::Two)?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_passed() {
+        let check = |actual: &str| Check {
+            day: 1,
+            part: Part::One,
+            expected: "53334".to_string(),
+            actual: actual.to_string(),
+        };
+
+        assert!(check("53334").passed());
+        assert!(!check("53335").passed());
+    }
+}
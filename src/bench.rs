@@ -0,0 +1,209 @@
+use std::{
+    fmt::Display,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use anyhow::{bail, Result};
+
+use crate::solution::Part;
+
+/// How many times to run something before and while timing it.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Config {
+    pub warmup: usize,
+    pub runs: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            runs: 10,
+        }
+    }
+}
+
+/// The distribution of a set of timed runs.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Result<Self> {
+        if samples.is_empty() {
+            bail!("Cannot summarise zero runs");
+        }
+        samples.sort();
+
+        // nearest rank, so small samples report a time that actually happened
+        let rank = |p: usize| samples[(samples.len() * p).div_ceil(100).max(1) - 1];
+
+        Ok(Self {
+            runs: samples.len(),
+            min: samples[0],
+            median: rank(50),
+            p95: rank(95),
+            max: samples[samples.len() - 1],
+        })
+    }
+}
+
+/// Times `f` after a few untimed warmup runs.
+pub fn measure<T>(config: &Config, mut f: impl FnMut() -> Result<T>) -> Result<Stats> {
+    for _ in 0..config.warmup {
+        black_box(f()?);
+    }
+
+    let mut samples = Vec::with_capacity(config.runs);
+    for _ in 0..config.runs {
+        let start = Instant::now();
+        black_box(f()?);
+        samples.push(start.elapsed());
+    }
+
+    Stats::from_samples(samples)
+}
+
+/// The step of a solution being timed.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Stage {
+    Parse,
+    Part(Part),
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parse => write!(f, "parse"),
+            Self::Part(part) => write!(f, "part{}", part),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Timing {
+    pub day: usize,
+    pub stage: Stage,
+    pub stats: Stats,
+}
+
+/// One JSON object per timing, with durations in nanoseconds.
+pub fn to_json(timings: &[Timing]) -> String {
+    let rows = timings
+        .iter()
+        .map(|t| {
+            format!(
+                r#"  {{"day": {}, "stage": "{}", "runs": {}, "min_ns": {}, "median_ns": {}, "p95_ns": {}, "max_ns": {}}}"#,
+                t.day,
+                t.stage,
+                t.stats.runs,
+                t.stats.min.as_nanos(),
+                t.stats.median.as_nanos(),
+                t.stats.p95.as_nanos(),
+                t.stats.max.as_nanos()
+            )
+        })
+        .collect::<Vec<_>>();
+
+    if rows.is_empty() {
+        "[]".to_string()
+    } else {
+        format!("[\n{}\n]", rows.join(",\n"))
+    }
+}
+
+/// Lines up the timings for reading in a terminal.
+pub fn to_table(timings: &[Timing]) -> String {
+    let mut table = format!(
+        "{:>3}  {:<6}  {:>5}  {:>12}  {:>12}  {:>12}\n",
+        "day", "stage", "runs", "min", "median", "p95"
+    );
+
+    for t in timings {
+        table.push_str(&format!(
+            "{:>3}  {:<6}  {:>5}  {:>12}  {:>12}  {:>12}\n",
+            t.day,
+            t.stage.to_string(),
+            t.stats.runs,
+            format!("{:.2?}", t.stats.min),
+            format!("{:.2?}", t.stats.median),
+            format!("{:.2?}", t.stats.p95)
+        ));
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn test_from_samples() -> Result<()> {
+        let stats = Stats::from_samples((1..=20).rev().map(ms).collect())?;
+        assert_eq!(
+            stats,
+            Stats {
+                runs: 20,
+                min: ms(1),
+                median: ms(10),
+                p95: ms(19),
+                max: ms(20),
+            }
+        );
+
+        let single = Stats::from_samples(vec![ms(7)])?;
+        assert_eq!((single.median, single.p95), (ms(7), ms(7)));
+
+        assert!(Stats::from_samples(Vec::new()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_measure() -> Result<()> {
+        let mut calls = 0;
+        let stats = measure(&Config { warmup: 2, runs: 5 }, || {
+            calls += 1;
+            Ok(calls)
+        })?;
+
+        assert_eq!(calls, 7);
+        assert_eq!(stats.runs, 5);
+        assert!(stats.min <= stats.median && stats.median <= stats.p95);
+
+        assert!(measure(&Config::default(), || -> Result<()> { bail!("boom") }).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_output() -> Result<()> {
+        let timings = vec![Timing {
+            day: 7,
+            stage: Stage::Part(Part::Two),
+            stats: Stats::from_samples(vec![ms(1), ms(3), ms(2)])?,
+        }];
+
+        assert_eq!(
+            to_json(&timings),
+            r#"[
+  {"day": 7, "stage": "part2", "runs": 3, "min_ns": 1000000, "median_ns": 2000000, "p95_ns": 3000000, "max_ns": 3000000}
+]"#
+        );
+        assert_eq!(to_json(&[]), "[]");
+
+        let table = to_table(&timings);
+        assert_eq!(table.lines().count(), 2);
+        assert!(table.lines().nth(1).is_some_and(|l| l.contains("part2")));
+        Ok(())
+    }
+}
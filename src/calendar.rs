@@ -0,0 +1,340 @@
+use std::{
+    any::Any,
+    cell::Cell,
+    fmt::Display,
+    panic::{self, catch_unwind, AssertUnwindSafe},
+    sync::Once,
+    thread,
+    time::{Duration, Instant},
+};
+
+use rayon::prelude::*;
+
+use crate::{
+    io::InputError,
+    solution::{Part, Runnable},
+};
+
+/// What the unsolved day templates panic with.
+const UNSOLVED: &str = "You have to solve the puzzle first!";
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Status {
+    Solved,
+    NotSolved,
+    NoInput,
+    Failed(String),
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Solved => write!(f, "ok"),
+            Self::NotSolved => write!(f, "not solved"),
+            Self::NoInput => write!(f, "no input"),
+            Self::Failed(reason) => write!(f, "failed: {}", reason),
+        }
+    }
+}
+
+/// The outcome of running one part of one day.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Row {
+    pub day: usize,
+    pub part: Part,
+    pub answer: Option<String>,
+    pub time: Duration,
+    pub status: Status,
+}
+
+/// Runs every part of every day on the rayon thread pool.
+///
+/// Each day's input is parsed once, and a panic only takes down the part it
+/// happened in. Panics caught here are reported in the rows rather than
+/// printed, so the unsolved templates don't fill the terminal with
+/// backtraces.
+pub fn run(
+    days: &[&dyn Runnable],
+    parts: &[Part],
+    input: impl Fn(usize) -> Result<String, InputError> + Sync,
+) -> Vec<Row> {
+    days.par_iter()
+        .flat_map_iter(|day| run_day(*day, parts, &input))
+        .collect()
+}
+
+fn run_day(
+    day: &dyn Runnable,
+    parts: &[Part],
+    input: impl Fn(usize) -> Result<String, InputError>,
+) -> Vec<Row> {
+    let row = |part, answer, time, status| Row {
+        day: day.day(),
+        part,
+        answer,
+        time,
+        status,
+    };
+    let every_part = |status: Status| {
+        parts
+            .iter()
+            .map(|&part| row(part, None, Duration::ZERO, status.clone()))
+            .collect()
+    };
+
+    let input = match input(day.day()) {
+        Ok(input) => input,
+        Err(e) => {
+            return every_part(match e {
+                InputError::Missing { .. } | InputError::Empty { .. } => Status::NoInput,
+                e => Status::Failed(e.to_string()),
+            });
+        }
+    };
+
+    let solve = match quietly(|| day.prepare(&input)) {
+        Ok(Ok(solve)) => solve,
+        Ok(Err(e)) => return every_part(Status::Failed(e.to_string())),
+        Err(payload) => return every_part(panicked(payload)),
+    };
+
+    parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let result = quietly(|| solve(part));
+            let time = start.elapsed();
+
+            match result {
+                Ok(Ok(answer)) => row(part, Some(answer), time, Status::Solved),
+                Ok(Err(e)) => row(part, None, time, Status::Failed(e.to_string())),
+                Err(payload) => row(part, None, time, panicked(payload)),
+            }
+        })
+        .collect()
+}
+
+thread_local! {
+    static QUIET: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Catches a panic in `f` without the panic hook printing it. The hook is
+/// only wrapped once, and panics on any other thread, or outside of here,
+/// still reach the original one.
+fn quietly<T>(f: impl FnOnce() -> T) -> thread::Result<T> {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !QUIET.with(Cell::get) {
+                hook(info);
+            }
+        }));
+    });
+
+    let was_quiet = QUIET.with(|quiet| quiet.replace(true));
+    let result = catch_unwind(AssertUnwindSafe(f));
+    QUIET.with(|quiet| quiet.set(was_quiet));
+    result
+}
+
+fn panicked(payload: Box<dyn Any + Send>) -> Status {
+    let message = panic_message(payload.as_ref());
+    if message.contains(UNSOLVED) {
+        Status::NotSolved
+    } else {
+        Status::Failed(format!("panicked: {}", message))
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// Lines up the rows for reading in a terminal.
+pub fn to_table(rows: &[Row]) -> String {
+    let mut table = format!(
+        "{:>3}  {:>4}  {:>20}  {:>10}  {}\n",
+        "day", "part", "answer", "time", "status"
+    );
+
+    for row in rows {
+        table.push_str(&format!(
+            "{:>3}  {:>4}  {:>20}  {:>10}  {}\n",
+            row.day,
+            row.part.number(),
+            row.answer.as_deref().unwrap_or("-"),
+            format!("{:.2?}", row.time),
+            row.status
+        ));
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        path::PathBuf,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    use anyhow::{bail, Result};
+
+    use super::*;
+    use crate::solution::Solution;
+
+    struct Answered;
+
+    impl Solution for Answered {
+        const DAY: usize = 1;
+
+        type Input = usize;
+        type Part1 = usize;
+        type Part2 = usize;
+
+        fn parse(&self, input: &str) -> Result<Self::Input> {
+            Ok(input.len())
+        }
+
+        fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+            Ok(*input)
+        }
+
+        fn part2(&self, _input: &Self::Input) -> Result<Self::Part2> {
+            unimplemented!("You have to solve the puzzle first!")
+        }
+    }
+
+    struct Broken;
+
+    impl Solution for Broken {
+        const DAY: usize = 2;
+
+        type Input = ();
+        type Part1 = usize;
+        type Part2 = usize;
+
+        fn parse(&self, _input: &str) -> Result<Self::Input> {
+            Ok(())
+        }
+
+        fn part1(&self, _input: &Self::Input) -> Result<Self::Part1> {
+            bail!("no answer")
+        }
+
+        fn part2(&self, _input: &Self::Input) -> Result<Self::Part2> {
+            panic!("index out of bounds")
+        }
+    }
+
+    #[test]
+    fn test_run() {
+        let days: [&dyn Runnable; 3] = [&Answered, &Broken, &Answered];
+        let input = |day| match day {
+            1 => Ok("abc".to_string()),
+            _ => Err(InputError::Empty {
+                path: PathBuf::from("input"),
+            }),
+        };
+
+        let statuses = run(&days, &Part::values(), input)
+            .into_iter()
+            .map(|row| (row.day, row.part, row.answer, row.status))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            statuses,
+            vec![
+                (1, Part::One, Some("3".to_string()), Status::Solved),
+                (1, Part::Two, None, Status::NotSolved),
+                (2, Part::One, None, Status::NoInput),
+                (2, Part::Two, None, Status::NoInput),
+                (1, Part::One, Some("3".to_string()), Status::Solved),
+                (1, Part::Two, None, Status::NotSolved),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_failures() {
+        let rows = run(&[&Broken], &Part::values(), |_| Ok(String::new()));
+
+        assert_eq!(rows[0].status, Status::Failed("no answer".to_string()));
+        assert_eq!(
+            rows[1].status,
+            Status::Failed("panicked: index out of bounds".to_string())
+        );
+    }
+
+    #[derive(Default)]
+    struct Counted {
+        parses: AtomicUsize,
+    }
+
+    impl Solution for Counted {
+        const DAY: usize = 3;
+
+        type Input = ();
+        type Part1 = usize;
+        type Part2 = usize;
+
+        fn parse(&self, input: &str) -> Result<Self::Input> {
+            self.parses.fetch_add(1, Ordering::SeqCst);
+            if input.is_empty() {
+                bail!("nothing to parse");
+            }
+            Ok(())
+        }
+
+        fn part1(&self, _input: &Self::Input) -> Result<Self::Part1> {
+            Ok(1)
+        }
+
+        fn part2(&self, _input: &Self::Input) -> Result<Self::Part2> {
+            Ok(2)
+        }
+    }
+
+    #[test]
+    fn test_parses_once() {
+        let day = Counted::default();
+        let rows = run(&[&day], &Part::values(), |_| Ok("x".to_string()));
+        assert_eq!(rows.len(), 2);
+        assert_eq!(day.parses.load(Ordering::SeqCst), 1);
+
+        let rows = run(&[&day], &Part::values(), |_| Ok(String::new()));
+        assert!(rows
+            .iter()
+            .all(|row| row.status == Status::Failed("nothing to parse".to_string())));
+        assert_eq!(day.parses.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_quietly_restores() {
+        assert!(quietly(|| panic!("inner")).is_err());
+        assert!(!QUIET.with(Cell::get));
+        assert_eq!(quietly(|| quietly(|| 1).unwrap()).unwrap(), 1);
+        assert!(!QUIET.with(Cell::get));
+    }
+
+    #[test]
+    fn test_to_table() {
+        let table = to_table(&[Row {
+            day: 7,
+            part: Part::Two,
+            answer: None,
+            time: Duration::ZERO,
+            status: Status::NotSolved,
+        }]);
+
+        assert_eq!(table.lines().count(), 2);
+        assert!(table.ends_with("not solved\n"));
+    }
+}
@@ -0,0 +1,235 @@
+use std::{
+    env, fs,
+    path::PathBuf,
+    sync::Mutex,
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+
+use crate::{puzzle::PuzzleId, solution::Part};
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+// https://www.reddit.com/r/adventofcode/wiki/faqs/automation
+const USER_AGENT: &str = concat!(
+    "github.com/mulchy/advent-2023 ",
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION")
+);
+
+// shared by every client so a loop over days can't hammer the server
+static LAST_REQUEST: Mutex<Option<Instant>> = Mutex::new(None);
+
+/// Talks to adventofcode.com (or whatever `ADVENT_BASE_URL` points at)
+/// on behalf of the logged in user.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+    min_interval: Duration,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+            min_interval: Duration::from_secs(5),
+        }
+    }
+
+    /// Minimum time to wait between two requests.
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    pub fn from_env() -> Result<Self> {
+        let base_url = env::var("ADVENT_BASE_URL").unwrap_or(DEFAULT_BASE_URL.to_string());
+        Ok(Self::new(&base_url, &session()?))
+    }
+
+    pub fn input(&self, puzzle: PuzzleId) -> Result<String> {
+        self.get(&format!("/{}/day/{}/input", puzzle.year, puzzle.day))
+            .with_context(|| format!("Could not download the input for {}", puzzle))
+    }
+
+    /// Posts an answer, returning the page the server responds with.
+    pub fn answer(&self, puzzle: PuzzleId, part: Part, answer: &str) -> Result<String> {
+        self.post(
+            &format!("/{}/day/{}/answer", puzzle.year, puzzle.day),
+            &[("level", &part.to_string()), ("answer", answer)],
+        )
+        .with_context(|| format!("Could not submit the answer for {} part {}", puzzle, part))
+    }
+
+    fn get(&self, path: &str) -> Result<String> {
+        self.throttle();
+
+        let url = format!("{}{}", self.base_url, path);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+
+        Self::body(&url, response)
+    }
+
+    fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<String> {
+        self.throttle();
+
+        let url = format!("{}{}", self.base_url, path);
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(form);
+
+        Self::body(&url, response)
+    }
+
+    fn body(url: &str, response: Result<ureq::Response, ureq::Error>) -> Result<String> {
+        match response {
+            Ok(response) => Ok(response.into_string()?),
+            Err(ureq::Error::Status(400, _)) => {
+                bail!("The session token was rejected, it has probably expired")
+            }
+            Err(ureq::Error::Status(404, _)) => bail!("{} is not available yet", url),
+            Err(ureq::Error::Status(code, response)) => bail!(
+                "{} responded with {}: {}",
+                url,
+                code,
+                response.into_string().unwrap_or_default().trim()
+            ),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn throttle(&self) {
+        let mut last = LAST_REQUEST.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(elapsed) = last.map(|l| l.elapsed()) {
+            if elapsed < self.min_interval {
+                thread::sleep(self.min_interval - elapsed);
+            }
+        }
+        *last = Some(Instant::now());
+    }
+}
+
+fn session_files() -> Vec<PathBuf> {
+    let mut files = vec![PathBuf::from(".session")];
+
+    let config = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")));
+    if let Some(config) = config {
+        files.push(config.join("advent").join("session"));
+    }
+
+    files
+}
+
+/// The session cookie, taken from `ADVENT_SESSION` or the first session file found.
+pub fn session() -> Result<String> {
+    if let Ok(session) = env::var("ADVENT_SESSION") {
+        return Ok(session);
+    }
+
+    let files = session_files();
+    files
+        .iter()
+        .find_map(|path| fs::read_to_string(path).ok())
+        .ok_or(anyhow!(
+            "No session token found, set ADVENT_SESSION or save it to one of {:?}",
+            files
+        ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_server::MockServer;
+
+    #[test]
+    fn test_input() -> Result<()> {
+        let server = MockServer::start(vec![(200, "1abc2\n")]);
+        let client = Client::new(&server.base_url, "abc123\n").with_min_interval(Duration::ZERO);
+
+        assert_eq!(client.input(PuzzleId::new(2015, 1)?)?, "1abc2\n");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path, "/2015/day/1/input");
+        assert_eq!(requests[0].header("Cookie"), Some("session=abc123"));
+        assert_eq!(requests[0].header("User-Agent"), Some(USER_AGENT));
+        Ok(())
+    }
+
+    #[test]
+    fn test_answer() -> Result<()> {
+        let server = MockServer::start(vec![(
+            200,
+            "<article><p>That's the right answer!</p></article>",
+        )]);
+        let client = Client::new(&server.base_url, "abc123").with_min_interval(Duration::ZERO);
+
+        assert_eq!(
+            client.answer(PuzzleId::day(7)?, Part::Two, "5905")?,
+            "<article><p>That's the right answer!</p></article>"
+        );
+
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/2023/day/7/answer");
+        assert_eq!(requests[0].header("Cookie"), Some("session=abc123"));
+        assert_eq!(requests[0].body, "level=2&answer=5905");
+        Ok(())
+    }
+
+    #[test]
+    fn test_input_errors() {
+        let server = MockServer::start(vec![
+            (
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+            ),
+            (
+                404,
+                "Please don't repeatedly request this endpoint before it unlocks!",
+            ),
+            (500, "Internal Server Error"),
+        ]);
+        let client = Client::new(&server.base_url, "abc123").with_min_interval(Duration::ZERO);
+
+        let expired = client.input(PuzzleId::day(1).unwrap()).unwrap_err();
+        assert!(format!("{:#}", expired).contains("session token was rejected"));
+
+        let locked = client.input(PuzzleId::day(25).unwrap()).unwrap_err();
+        assert!(format!("{:#}", locked).contains("not available yet"));
+
+        let broken = client.input(PuzzleId::day(3).unwrap()).unwrap_err();
+        assert!(format!("{:#}", broken).contains("500: Internal Server Error"));
+    }
+
+    #[test]
+    fn test_throttle() -> Result<()> {
+        let server = MockServer::start(vec![(200, "one"), (200, "two")]);
+        let client =
+            Client::new(&server.base_url, "abc123").with_min_interval(Duration::from_millis(200));
+
+        let start = Instant::now();
+        client.input(PuzzleId::day(1)?)?;
+        client.input(PuzzleId::day(2)?)?;
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        Ok(())
+    }
+}
@@ -0,0 +1,206 @@
+//! Finding where a repeatedly applied step starts going round in circles, so
+//! a simulation can skip straight to its billionth step.
+//!
+//! These all assume the step is deterministic and only ever reaches finitely
+//! many states, otherwise they never return.
+
+use std::{collections::HashMap, hash::Hash};
+
+/// The shape of a sequence `x0, f(x0), f(f(x0)), ...` that eventually repeats:
+/// `prefix` states that are never seen again, then the same `length` states
+/// over and over.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Cycle {
+    pub prefix: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The earliest step that is in the same state as step `n`.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.prefix {
+            n
+        } else {
+            self.prefix + (n - self.prefix) % self.length
+        }
+    }
+}
+
+/// Floyd's tortoise and hare, which only ever keeps two states around.
+pub fn floyd<S: PartialEq + Clone>(start: S, step: impl Fn(&S) -> S) -> Cycle {
+    let mut tortoise = step(&start);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&step(&hare));
+    }
+
+    // the hare is now a whole number of cycles ahead, so walking both at the
+    // same speed from here they meet where the cycle starts
+    let mut prefix = 0;
+    tortoise = start;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    let mut length = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+
+    Cycle { prefix, length }
+}
+
+/// Brent's algorithm, which finds the same as [`floyd`] with fewer steps.
+pub fn brent<S: PartialEq + Clone>(start: S, step: impl Fn(&S) -> S) -> Cycle {
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = start.clone();
+    let mut hare = step(&start);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    let mut prefix = 0;
+    tortoise = start.clone();
+    hare = start;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    Cycle { prefix, length }
+}
+
+/// Every state up to the first repeat, so any later step can be looked up
+/// without running the step again.
+#[derive(Debug, Clone)]
+pub struct History<S> {
+    pub states: Vec<S>,
+    pub cycle: Cycle,
+}
+
+impl<S> History<S> {
+    /// The state after `n` steps.
+    pub fn nth(&self, n: usize) -> &S {
+        &self.states[self.cycle.reduce(n)]
+    }
+
+    /// The states that repeat forever.
+    pub fn looping(&self) -> &[S] {
+        &self.states[self.cycle.prefix..]
+    }
+}
+
+/// Remembers every state until one comes round again. Uses more memory than
+/// [`floyd`] or [`brent`], but calls `step` the fewest times.
+pub fn find<S: Eq + Hash + Clone>(start: S, step: impl Fn(&S) -> S) -> History<S> {
+    let mut seen = HashMap::new();
+    let mut states = Vec::new();
+    let mut state = start;
+
+    loop {
+        if let Some(&prefix) = seen.get(&state) {
+            let length = states.len() - prefix;
+            return History {
+                states,
+                cycle: Cycle { prefix, length },
+            };
+        }
+
+        seen.insert(state.clone(), states.len());
+        let next = step(&state);
+        states.push(state);
+        state = next;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quickcheck::Arbitrary;
+
+    // a function on 0..n given by its table of values
+    #[derive(Debug, Clone)]
+    struct Table(Vec<usize>);
+
+    impl Arbitrary for Table {
+        fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+            let n = usize::arbitrary(g) % 50 + 1;
+            Table((0..n).map(|_| usize::arbitrary(g) % n).collect())
+        }
+    }
+
+    fn brute_force(table: &[usize]) -> Cycle {
+        let mut states = vec![0];
+        loop {
+            let next = table[*states.last().unwrap()];
+            if let Some(prefix) = states.iter().position(|&s| s == next) {
+                return Cycle {
+                    prefix,
+                    length: states.len() - prefix,
+                };
+            }
+            states.push(next);
+        }
+    }
+
+    #[test]
+    fn test_rho() {
+        // 0 -> 1 -> 2 -> 3 -> 4 -> 2
+        let step = |&x: &usize| if x == 4 { 2 } else { x + 1 };
+        let expected = Cycle {
+            prefix: 2,
+            length: 3,
+        };
+
+        assert_eq!(floyd(0, step), expected);
+        assert_eq!(brent(0, step), expected);
+
+        let history = find(0, step);
+        assert_eq!(history.cycle, expected);
+        assert_eq!(history.looping(), &[2, 3, 4]);
+        assert_eq!(*history.nth(1), 1);
+        assert_eq!(*history.nth(1_000_000_000), 4);
+    }
+
+    #[test]
+    fn test_fixed_point() {
+        let expected = Cycle {
+            prefix: 0,
+            length: 1,
+        };
+        assert_eq!(floyd(7, |&x| x), expected);
+        assert_eq!(brent(7, |&x| x), expected);
+        assert_eq!(find(7, |&x| x).cycle, expected);
+    }
+
+    #[quickcheck_macros::quickcheck]
+    fn test_all_agree(table: Table) -> bool {
+        let expected = brute_force(&table.0);
+        let step = |&x: &usize| table.0[x];
+
+        floyd(0, step) == expected && brent(0, step) == expected && find(0, step).cycle == expected
+    }
+
+    #[quickcheck_macros::quickcheck]
+    fn test_nth_matches_stepping(table: Table, n: u8) -> bool {
+        let history = find(0, |&x: &usize| table.0[x]);
+        let state = (0..n).fold(0, |x, _| table.0[x]);
+        *history.nth(n as usize) == state
+    }
+}
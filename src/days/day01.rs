@@ -1,15 +1,28 @@
-#![feature(test)]
-
 use std::collections::HashMap;
 
-use advent::io;
+use crate::solution::Solution;
 use anyhow::Result;
 
-fn main() -> Result<()> {
-    let input = io::for_day(1)?;
-    println!("{}", part1(&input)?);
-    println!("{}", part2(&input)?);
-    Ok(())
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: usize = 1;
+
+    type Input = String;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        part2(input)
+    }
 }
 
 fn part1(input: &str) -> Result<i32> {
@@ -130,10 +143,8 @@ fn find_digits_2(s: &str) -> Vec<char> {
 
 #[cfg(test)]
 mod tests {
-    extern crate test;
-
     use super::*;
-    use test::Bencher;
+    use crate::bench::{measure, Config};
 
     #[test]
     fn test_part1() -> Result<()> {
@@ -170,13 +181,25 @@ zoneight234
         assert_eq!(find_digits_2(example_input), expected_output);
     }
 
-    #[bench]
-    fn bench_find_digits(b: &mut Bencher) {
-        b.iter(|| find_digits("5xkqkjfjgksflfcqrgrhmfxflscsxsrdhxgpfivetxgpzzlfzj1"));
-    }
-
-    #[bench]
-    fn bench_find_digits_2(b: &mut Bencher) {
-        b.iter(|| find_digits_2("5xkqkjfjgksflfcqrgrhmfxflscsxsrdhxgpfivetxgpzzlfzj1"));
+    // cargo test --release bench_find_digits -- --ignored --nocapture
+    #[test]
+    #[ignore]
+    fn bench_find_digits() -> Result<()> {
+        let line = "5xkqkjfjgksflfcqrgrhmfxflscsxsrdhxgpfivetxgpzzlfzj1";
+        let config = Config {
+            warmup: 1000,
+            runs: 10000,
+        };
+
+        for (name, stats) in [
+            ("find_digits", measure(&config, || Ok(find_digits(line)))?),
+            (
+                "find_digits_2",
+                measure(&config, || Ok(find_digits_2(line)))?,
+            ),
+        ] {
+            println!("{}: median {:?}, p95 {:?}", name, stats.median, stats.p95);
+        }
+        Ok(())
     }
 }
@@ -1,12 +1,27 @@
-use advent::io;
+use crate::solution::Solution;
 use anyhow::{anyhow, Result};
 use regex::Regex;
 
-fn main() -> Result<()> {
-    let input = io::for_day(2)?;
-    println!("{}", part1(&input));
-    println!("{}", part2(&input));
-    Ok(())
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: usize = 2;
+
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        Ok(part1(input))
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        Ok(part2(input))
+    }
 }
 
 fn count(re: Regex, input: &str) -> usize {
@@ -1,13 +1,28 @@
 use std::collections::{HashMap, HashSet};
 
-use advent::io;
+use crate::solution::Solution;
 use anyhow::Result;
 
-fn main() -> Result<()> {
-    let input = io::for_day(3)?;
-    println!("{}", part1(&input));
-    println!("{}", part2(&input));
-    Ok(())
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: usize = 3;
+
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        Ok(part1(input))
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        Ok(part2(input))
+    }
 }
 
 fn part1(input: &str) -> usize {
@@ -94,12 +109,12 @@ fn boundary(start: usize, end: usize, width: usize, max: usize) -> Vec<usize> {
     }
 
     // left
-    if start % width != 0 {
+    if !start.is_multiple_of(width) {
         output.push(start - 1);
     }
 
     // right
-    if end % width != 0 {
+    if !end.is_multiple_of(width) {
         output.push(end);
     }
 
@@ -114,19 +129,19 @@ fn boundary(start: usize, end: usize, width: usize, max: usize) -> Vec<usize> {
 
     // corners
     // top left
-    if start > width && start % width != 0 {
+    if start > width && !start.is_multiple_of(width) {
         output.push(start - width - 1);
     }
     // top right
-    if start > width && end % width != 0 {
+    if start > width && !end.is_multiple_of(width) {
         output.push(end - width);
     }
     // bottom left
-    if end < max - width && start % width != 0 {
+    if end < max - width && !start.is_multiple_of(width) {
         output.push(start + width - 1);
     }
     // bottom right
-    if end < max - width && end % width != 0 {
+    if end < max - width && !end.is_multiple_of(width) {
         output.push(end + width);
     }
 
@@ -3,28 +3,46 @@ use std::{
     str::FromStr,
 };
 
-use advent::io;
-use anyhow::{anyhow, bail, Result};
-
-fn main() -> Result<()> {
-    let input = io::for_day(4)?;
-    println!("{}", part1(&input)?);
-    println!("{}", part2(&input)?);
-    Ok(())
+use crate::{parse::Text, solution::Solution};
+use anyhow::Result;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: usize = 4;
+
+    type Input = String;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        part2(input)
+    }
 }
 
 fn part1(input: &str) -> Result<i32> {
-    input
+    Text::new(input)
         .lines()
         .map(|l| {
-            let card = Card::from_str(l)?;
+            let card = Card::parse(l)?;
             Ok(card.score())
         })
         .sum::<Result<i32>>()
 }
 
 fn part2(input: &str) -> Result<i32> {
-    let cards: Vec<Card> = input.lines().flat_map(Card::from_str).collect();
+    let cards = Text::new(input)
+        .lines()
+        .map(Card::parse)
+        .collect::<Result<Vec<_>>>()?;
     let cards_by_id = cards
         .iter()
         .map(|c| (c.id, c.generate_copies()))
@@ -64,41 +82,23 @@ struct Card {
 impl FromStr for Card {
     type Err = anyhow::Error;
 
-    fn from_str(mut s: &str) -> Result<Self> {
-        s = s
-            .strip_prefix("Card")
-            .ok_or(anyhow!("missing prefix; {}", s))?;
-        let parts = s.trim().split(':').collect::<Vec<_>>();
-        let [id, rest] = parts.as_slice() else {
-            bail!("couldn't split into two parts: {}", s);
-        };
-
-        let id = id.parse::<i32>()?;
-
-        let parts = rest.split('|').collect::<Vec<_>>();
-        let [winning_numbers, numbers] = parts.as_slice() else {
-            bail!("couldn't split into winning numbers and numbers: {}", rest);
-        };
-
-        let winning_numbers = winning_numbers
-            .split_whitespace()
-            .flat_map(|s| s.parse::<i32>())
-            .collect::<HashSet<_>>();
+    fn from_str(s: &str) -> Result<Self> {
+        Card::parse(Text::new(s))
+    }
+}
 
-        let numbers = numbers
-            .split_whitespace()
-            .flat_map(|s| s.parse::<i32>())
-            .collect::<Vec<_>>();
+impl Card {
+    fn parse(line: Text) -> Result<Self> {
+        let (id, rest) = line.strip_prefix("Card")?.split_once(":")?;
+        let (winning_numbers, numbers) = rest.split_once("|")?;
 
         Ok(Card {
-            id,
-            winning_numbers,
-            numbers,
+            id: id.parse()?,
+            winning_numbers: winning_numbers.numbers::<i32>()?.into_iter().collect(),
+            numbers: numbers.numbers()?,
         })
     }
-}
 
-impl Card {
     fn score(&self) -> i32 {
         let winners = self
             .numbers
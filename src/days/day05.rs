@@ -0,0 +1,723 @@
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    fmt::Display,
+    ops::Range,
+    str::FromStr,
+};
+
+use crate::{
+    interval::{IntervalSet, PiecewiseMap},
+    parse::Text,
+    search,
+    solution::Solution,
+};
+use anyhow::{anyhow, bail, Error, Result};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: usize = 5;
+
+    type Input = Input;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Input::from_str(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        part2(input)
+    }
+}
+
+fn part1(input: &Input) -> Result<i64> {
+    input
+        .map_seeds()
+        .into_iter()
+        .min()
+        .ok_or(anyhow!("No data"))
+}
+
+fn part2(input: &Input) -> Result<i64> {
+    input.map_seed_ranges()?.min().ok_or(anyhow!("No data"))
+}
+
+#[derive(Debug, PartialEq)]
+struct Mapping {
+    domain: Range<i64>,
+    offset: i64,
+}
+
+impl Mapping {
+    fn new(domain: Range<i64>, offset: i64) -> Self {
+        Self { domain, offset }
+    }
+
+    /// The same shift run backwards, from where values land to where they started.
+    fn invert(&self) -> Self {
+        let image = self.domain.start + self.offset..self.domain.end + self.offset;
+        Self::new(image, -self.offset)
+    }
+
+    fn map(&self, value: i64) -> i64 {
+        if self.domain.contains(&value) {
+            value + self.offset
+        } else {
+            value
+        }
+    }
+}
+
+struct Map {
+    source: String,
+    dest: String,
+    inner: Vec<Mapping>, // checked to be disjoint by `validate`
+}
+
+impl Map {
+    /// The map from `dest` back to `source`, as long as no two values land
+    /// in the same place.
+    fn invert(&self) -> Option<Self> {
+        self.piecewise().ok()?.invert()?;
+
+        Some(Self {
+            source: self.dest.clone(),
+            dest: self.source.clone(),
+            inner: self.inner.iter().map(Mapping::invert).collect(),
+        })
+    }
+
+    fn piecewise(&self) -> Result<PiecewiseMap> {
+        PiecewiseMap::new(self.inner.iter().map(|m| (m.domain.clone(), m.offset)))
+    }
+
+    fn map(&self, value: i64) -> i64 {
+        self.inner
+            .iter()
+            .find(|m| m.domain.contains(&value))
+            .map(|m| m.map(value))
+            .unwrap_or(value)
+    }
+}
+
+impl Map {
+    fn parse(section: Text) -> Result<Self> {
+        let mut lines = section.lines();
+
+        let header = lines
+            .next()
+            .ok_or_else(|| section.error("expected a map header"))?;
+        let [source, _, dest] = header.trim().strip_suffix("map:")?.trim().split_n("-")?;
+
+        let mappings = lines
+            .map(|line| {
+                let [dest_start, source_start, length] = line
+                    .numbers::<i64>()?
+                    .try_into()
+                    .map_err(|_| line.error("expected three numbers"))?;
+
+                let offset = dest_start - source_start;
+                let domain = source_start..source_start + length;
+
+                Ok(Mapping::new(domain, offset))
+            })
+            .collect::<Result<Vec<Mapping>>>()?;
+
+        Ok(Map {
+            source: source.to_string(),
+            dest: dest.to_string(),
+            inner: mappings,
+        })
+    }
+}
+
+impl FromStr for Map {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Map::parse(Text::new(s))
+    }
+}
+
+pub struct Input {
+    start: String,
+    seeds: Vec<i64>,
+    maps_by_source: HashMap<String, Map>,
+}
+
+impl Input {
+    fn next_seed(&self, source: &str, seed: i64) -> Option<(String, i64)> {
+        self.maps_by_source
+            .get(source)
+            .map(|m| (m.dest.clone(), m.map(seed)))
+    }
+
+    /// The seeds line read as `start length` pairs, since the real almanac
+    /// covers far too many seeds to map one at a time.
+    fn seed_ranges(&self) -> Result<IntervalSet<i64>> {
+        if !self.seeds.len().is_multiple_of(2) {
+            bail!(
+                "{} seed numbers can't be split into pairs",
+                self.seeds.len()
+            );
+        }
+
+        Ok(self
+            .seeds
+            .chunks(2)
+            .map(|pair| pair[0]..pair[0] + pair[1])
+            .collect())
+    }
+
+    fn map_seed_ranges(&self) -> Result<IntervalSet<i64>> {
+        Ok(self
+            .map_between(&self.start, END)?
+            .apply_set(&self.seed_ranges()?))
+    }
+
+    /// Every `from` value that ends up in `targets` once mapped on to `to`,
+    /// found by running each map on the way backwards.
+    pub fn trace_back(
+        &self,
+        from: &str,
+        to: &str,
+        targets: &IntervalSet<i64>,
+    ) -> Result<IntervalSet<i64>> {
+        let backwards = self.path(from, to)?.windows(2).rev().try_fold(
+            PiecewiseMap::identity(),
+            |combined, pair| {
+                let map = &self.maps_by_source[pair[0]];
+                let inverse = map.invert().ok_or_else(|| {
+                    anyhow!(
+                        "the {} map sends more than one {} to the same {}, so it can't be run backwards",
+                        map.source,
+                        map.source,
+                        map.dest
+                    )
+                })?;
+                Ok::<_, Error>(combined.then(&inverse.piecewise()?))
+            },
+        )?;
+
+        Ok(backwards.apply_set(targets))
+    }
+
+    /// The seeds, read as ranges like part 2 does, that end up at any of `locations`.
+    pub fn seeds_for_locations(&self, locations: &IntervalSet<i64>) -> Result<IntervalSet<i64>> {
+        Ok(self
+            .trace_back(&self.start, END, locations)?
+            .intersection(&self.seed_ranges()?))
+    }
+
+    /// Every map on the way from one category to another, rolled into one.
+    pub fn map_between(&self, from: &str, to: &str) -> Result<PiecewiseMap> {
+        self.path(from, to)?
+            .windows(2)
+            .try_fold(PiecewiseMap::identity(), |combined, pair| {
+                Ok(combined.then(&self.maps_by_source[pair[0]].piecewise()?))
+            })
+    }
+
+    /// The categories passed through on the way from `from` to `to`.
+    fn path<'a>(&'a self, from: &'a str, to: &str) -> Result<Vec<&'a str>> {
+        search::bfs(
+            from,
+            |&category| self.maps_by_source.get(category).map(|m| m.dest.as_str()),
+            |&category| category == to,
+        )
+        .path()
+        .ok_or_else(|| anyhow!("no maps lead from {} to {}", from, to))
+    }
+
+    fn map_seeds(&self) -> Vec<i64> {
+        self.seeds
+            .iter()
+            .map(|&seed| {
+                let mut current = Some((self.start.clone(), seed));
+                let mut last = seed;
+
+                while let Some((source, value)) = current {
+                    last = value;
+                    current = self.next_seed(&source, value);
+                }
+
+                last
+            })
+            .collect()
+    }
+}
+
+impl FromStr for Input {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let text = Text::new(s);
+        let mut sections = text.sections();
+        let header = sections
+            .next()
+            .ok_or_else(|| text.error("expected a seeds line"))?;
+
+        let (name, seeds) = header.key_value(":")?;
+        let name = name.as_str();
+        let name = name.strip_suffix('s').unwrap_or(name); // strip plural
+        let seeds = seeds.numbers::<i64>()?;
+
+        let maps = sections.map(Map::parse).collect::<Result<Vec<Map>>>()?;
+
+        let problems = validate(name, &maps);
+        if !problems.is_empty() {
+            let problems = problems
+                .iter()
+                .map(|p| p.to_string())
+                .collect::<Vec<_>>()
+                .join("\n");
+            bail!("the almanac doesn't add up:\n{}", problems);
+        }
+
+        let maps_by_source: HashMap<String, Map> =
+            maps.into_iter().map(|m| (m.source.clone(), m)).collect();
+
+        Ok(Input {
+            start: name.to_string(),
+            seeds,
+            maps_by_source,
+        })
+    }
+}
+
+/// Where every chain of maps is meant to end up.
+const END: &str = "location";
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum Problem {
+    Overlap {
+        category: String,
+        first: Range<i64>,
+        second: Range<i64>,
+    },
+    DuplicateSource(String),
+    Unreachable(String),
+    Missing(String),
+    Cycle(Vec<String>),
+}
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Problem::Overlap {
+                category,
+                first,
+                second,
+            } => write!(
+                f,
+                "the {} map sends {:?} and {:?} to two places at once",
+                category, first, second
+            ),
+            Problem::DuplicateSource(category) => {
+                write!(f, "there is more than one {} map", category)
+            }
+            Problem::Unreachable(category) => {
+                write!(f, "nothing ever turns into a {}", category)
+            }
+            Problem::Missing(category) => {
+                write!(f, "there is no {} map to get on to {}", category, END)
+            }
+            Problem::Cycle(categories) => {
+                write!(
+                    f,
+                    "the maps go round in circles: {}",
+                    categories.join(" -> ")
+                )
+            }
+        }
+    }
+}
+
+/// Everything that would make the maps from `start` not lead to exactly one
+/// place for each value.
+fn validate(start: &str, maps: &[Map]) -> Vec<Problem> {
+    let mut problems = Vec::new();
+    let mut dests: HashMap<&str, &str> = HashMap::new();
+
+    for map in maps {
+        match dests.entry(&map.source) {
+            Entry::Occupied(_) => problems.push(Problem::DuplicateSource(map.source.clone())),
+            Entry::Vacant(entry) => {
+                entry.insert(&map.dest);
+            }
+        }
+
+        let mut domains = map
+            .inner
+            .iter()
+            .map(|m| &m.domain)
+            .filter(|d| !d.is_empty())
+            .collect::<Vec<_>>();
+        domains.sort_by_key(|d| d.start);
+
+        // compare each against whichever earlier one reaches furthest, since
+        // a long range can overlap several that come after it
+        let mut furthest: Option<&Range<i64>> = None;
+        for domain in domains {
+            if let Some(previous) = furthest.filter(|p| domain.start < p.end) {
+                problems.push(Problem::Overlap {
+                    category: map.source.clone(),
+                    first: previous.clone(),
+                    second: domain.clone(),
+                });
+            }
+            if furthest.is_none_or(|p| domain.end > p.end) {
+                furthest = Some(domain);
+            }
+        }
+    }
+
+    let next = |category: &&str| dests.get(category).copied();
+
+    let reachable = search::bfs(start, next, |_| false);
+    for map in maps {
+        if reachable.distance(&map.source.as_str()).is_none() {
+            problems.push(Problem::Unreachable(map.source.clone()));
+        }
+    }
+
+    // each category only leads to one other, so just follow them along
+    let mut chain = vec![start];
+    while let Some(category) = chain.last().and_then(next) {
+        if let Some(i) = chain.iter().position(|&c| c == category) {
+            let mut cycle = chain[i..].to_vec();
+            cycle.push(category);
+            problems.push(Problem::Cycle(
+                cycle.into_iter().map(String::from).collect(),
+            ));
+            return problems;
+        }
+        chain.push(category);
+    }
+
+    match chain.last() {
+        Some(&last) if last != END => problems.push(Problem::Missing(last.to_string())),
+        _ => {}
+    }
+
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use std::vec;
+
+    use super::*;
+    const EXAMPLE_INPUT: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+    #[test]
+    fn test_map_from_str() -> Result<()> {
+        let input = "seed-to-soil map:
+50 98 2
+52 50 5";
+
+        let map = Map::from_str(input)?;
+        assert_eq!(map.source, "seed");
+        assert_eq!(map.dest, "soil");
+
+        assert_eq!(
+            map.inner,
+            vec![Mapping::new(98..100, -48), Mapping::new(50..55, 2)]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bad_numbers_are_reported() {
+        let error = Input::from_str("seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 5O 48")
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 5, column 4: invalid digit found in string in `5O`"
+        );
+    }
+
+    #[test]
+    fn test_validate() -> Result<()> {
+        let maps = |s: &str| {
+            Text::new(s)
+                .sections()
+                .map(Map::parse)
+                .collect::<Result<Vec<_>>>()
+        };
+
+        let (_, example) = EXAMPLE_INPUT.split_once("\n\n").unwrap();
+        assert_eq!(validate("seed", &maps(example)?), vec![]);
+
+        assert_eq!(
+            validate(
+                "seed",
+                &maps("seed-to-soil map:\n50 98 5\n52 50 50\n\nsoil-to-location map:\n1 2 3")?
+            ),
+            vec![Problem::Overlap {
+                category: "seed".to_string(),
+                first: 50..100,
+                second: 98..103
+            }]
+        );
+
+        // the first range overlaps both of the others, which don't touch, and
+        // an empty range doesn't send anything anywhere
+        assert_eq!(
+            validate(
+                "seed",
+                &maps("seed-to-location map:\n0 0 100\n0 10 10\n0 50 10\n0 30 0")?
+            ),
+            vec![
+                Problem::Overlap {
+                    category: "seed".to_string(),
+                    first: 0..100,
+                    second: 10..20
+                },
+                Problem::Overlap {
+                    category: "seed".to_string(),
+                    first: 0..100,
+                    second: 50..60
+                }
+            ]
+        );
+
+        assert_eq!(
+            validate(
+                "seed",
+                &maps("seed-to-soil map:\n\nseed-to-water map:\n\nwater-to-location map:")?
+            ),
+            vec![
+                Problem::DuplicateSource("seed".to_string()),
+                Problem::Unreachable("water".to_string()),
+                Problem::Missing("soil".to_string())
+            ]
+        );
+
+        assert_eq!(
+            validate(
+                "seed",
+                &maps("seed-to-soil map:\n\nsoil-to-water map:\n\nwater-to-soil map:")?
+            ),
+            vec![Problem::Cycle(vec![
+                "soil".to_string(),
+                "water".to_string(),
+                "soil".to_string()
+            ])]
+        );
+
+        let error = Input::from_str("seeds: 1\n\nseed-to-soil map:\n1 2 3")
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "the almanac doesn't add up:\nthere is no soil map to get on to location"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_between() -> Result<()> {
+        let input = Input::from_str(EXAMPLE_INPUT)?;
+
+        // the same steps as `test_next_seed`, skipping the ones in between
+        assert_eq!(input.map_between("soil", "humidity")?.apply(81), 78);
+        assert_eq!(input.map_between("seed", "location")?.apply(79), 82);
+        assert_eq!(
+            input.map_between("water", "water")?,
+            PiecewiseMap::identity()
+        );
+        assert!(input.map_between("location", "seed").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_invert_map() -> Result<()> {
+        let map = Map::from_str("seed-to-soil map:\n50 98 2\n52 50 48")?;
+        let inverse = map.invert().unwrap();
+
+        assert_eq!(
+            (inverse.source.as_str(), inverse.dest.as_str()),
+            ("soil", "seed")
+        );
+        assert!((0..120).all(|seed| inverse.map(map.map(seed)) == seed));
+
+        // 0 and 5 both end up at 5
+        let map = Map::from_str("seed-to-soil map:\n5 0 1")?;
+        assert!(map.invert().is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_seeds_for_locations() -> Result<()> {
+        let input = Input::from_str(EXAMPLE_INPUT)?;
+
+        // the part 2 answer comes from seed 82
+        let lowest = IntervalSet::from(46..47);
+        assert_eq!(
+            input.seeds_for_locations(&lowest)?,
+            IntervalSet::from(82..83)
+        );
+
+        // and part 1's seeds land where `map_seeds` says they do
+        for (seed, location) in input.seeds.iter().zip(input.map_seeds()) {
+            let locations = IntervalSet::from(location..location + 1);
+            assert!(input.trace_back("seed", END, &locations)?.contains(seed));
+        }
+
+        // 0 and 5 both end up at location 5, so there's no way back
+        let input = Input::from_str("seeds: 1\n\nseed-to-location map:\n5 0 1")?;
+        let error = input
+            .trace_back("seed", END, &IntervalSet::from(5..6))
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "the seed map sends more than one seed to the same location, so it can't be run backwards"
+        );
+        Ok(())
+    }
+
+    #[quickcheck_macros::quickcheck]
+    fn test_trace_back_round_trips(seed: u8) -> bool {
+        let mut input = Input::from_str(EXAMPLE_INPUT).unwrap();
+        input.seeds = vec![seed as i64];
+        let location = input.map_seeds()[0];
+
+        let traced = input
+            .trace_back("seed", END, &IntervalSet::from(location..location + 1))
+            .unwrap();
+
+        // every seed traced back really does end up at that location
+        let all_reach = traced.ranges().iter().flat_map(|r| r.clone()).all(|seed| {
+            input.seeds = vec![seed];
+            input.map_seeds() == vec![location]
+        });
+        traced.contains(&(seed as i64)) && all_reach
+    }
+
+    #[test]
+    fn test_input_from_str() -> Result<()> {
+        let input = Input::from_str(EXAMPLE_INPUT)?;
+
+        assert_eq!(input.start, "seed");
+        assert_eq!(input.seeds, vec![79, 14, 55, 13]);
+        assert_eq!(input.maps_by_source.len(), 7);
+        let mut keys = input.maps_by_source.keys().cloned().collect::<Vec<_>>();
+        keys.sort();
+        assert_eq!(
+            keys,
+            vec![
+                "fertilizer",
+                "humidity",
+                "light",
+                "seed",
+                "soil",
+                "temperature",
+                "water"
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_seed() -> Result<()> {
+        let input = Input::from_str(EXAMPLE_INPUT)?;
+        assert_eq!(input.next_seed("seed", 79), Some(("soil".to_owned(), 81)));
+        assert_eq!(
+            input.next_seed("soil", 81),
+            Some(("fertilizer".to_owned(), 81))
+        );
+        assert_eq!(
+            input.next_seed("fertilizer", 81),
+            Some(("water".to_owned(), 81))
+        );
+        assert_eq!(input.next_seed("water", 81), Some(("light".to_owned(), 74)));
+        assert_eq!(
+            input.next_seed("light", 74),
+            Some(("temperature".to_owned(), 78))
+        );
+        assert_eq!(
+            input.next_seed("temperature", 78),
+            Some(("humidity".to_owned(), 78))
+        );
+        assert_eq!(
+            input.next_seed("humidity", 78),
+            Some(("location".to_owned(), 82))
+        );
+        assert_eq!(input.next_seed("location", 82), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_walk() -> Result<()> {
+        let input = Input::from_str(EXAMPLE_INPUT)?;
+        let output = input.map_seeds();
+        assert_eq!(output, vec![82, 43, 86, 35]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        let expected_output = 35;
+
+        assert_eq!(part1(&Input::from_str(EXAMPLE_INPUT)?)?, expected_output);
+        Ok(())
+    }
+
+    #[test]
+    fn test_seed_ranges() -> Result<()> {
+        let input = Input::from_str(EXAMPLE_INPUT)?;
+        assert_eq!(input.seed_ranges()?.ranges(), &[55..68, 79..93]);
+        assert_eq!(input.map_seed_ranges()?.size(), 27);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        let expected_output = 46;
+
+        assert_eq!(part2(&Input::from_str(EXAMPLE_INPUT)?)?, expected_output);
+        Ok(())
+    }
+}
@@ -1,12 +1,27 @@
-use advent::io;
-use anyhow::{anyhow, Error, Result};
+use crate::{parse::Text, solution::Solution};
+use anyhow::{bail, Error, Result};
 use std::str::FromStr;
 
-fn main() -> Result<()> {
-    let input = io::for_day(6)?;
-    println!("{}", part1(&input)?);
-    println!("{}", part2(&input)?);
-    Ok(())
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: usize = 6;
+
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        part2(input)
+    }
 }
 
 fn part1(input: &str) -> Result<usize> {
@@ -14,31 +29,30 @@ fn part1(input: &str) -> Result<usize> {
 }
 
 fn part2(input: &str) -> Result<usize> {
-    let mut lines = input.lines();
-    let mut time = lines
-        .next()
-        .ok_or(anyhow!("No data"))?
-        .strip_prefix("Time:")
-        .ok_or(anyhow!("No data"))?
-        .to_string();
-    let mut distance = lines
-        .next()
-        .ok_or(anyhow!("No data"))?
-        .strip_prefix("Distance:")
-        .ok_or(anyhow!("No data"))?
-        .to_string();
-
-    time.retain(|c| !c.is_ascii_whitespace());
-    distance.retain(|c| !c.is_ascii_whitespace());
-
+    let [time, distance] = Text::new(input).trim().split_n("\n")?;
     let record = Record {
-        time: time.parse()?,
-        distance: distance.parse()?,
+        time: kerned(time, "Time:")?,
+        distance: kerned(distance, "Distance:")?,
     };
 
     Ok(record.number_of_ways_to_beat_the_record())
 }
 
+// the spaces between the digits are just bad kerning, so glue them together
+fn kerned(line: Text, label: &str) -> Result<i64> {
+    let digits = line
+        .trim()
+        .strip_prefix(label)?
+        .words()
+        .map(|word| {
+            word.parse::<u64>()?;
+            Ok(word.as_str())
+        })
+        .collect::<Result<String>>()?;
+
+    Ok(digits.parse()?)
+}
+
 #[derive(Debug, PartialEq, Copy, Clone)]
 struct Record {
     time: i64,
@@ -63,30 +77,21 @@ impl FromStr for Input {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self> {
-        let mut lines = s.lines();
-        let times = lines
-            .next()
-            .ok_or(anyhow!("No data"))?
-            .strip_prefix("Time:")
-            .ok_or(anyhow!("No data"))?;
-        let distances = lines
-            .next()
-            .ok_or(anyhow!("No data"))?
-            .strip_prefix("Distance:")
-            .ok_or(anyhow!("No data"))?;
-
-        let records: Result<Vec<Record>> = times
-            .split_whitespace()
-            .zip(distances.split_whitespace())
-            .map(|(t, d)| {
-                Ok(Record {
-                    time: t.parse()?,
-                    distance: d.parse()?,
-                })
-            })
+        let [times, distances] = Text::new(s).trim().split_n("\n")?;
+        let times = times.labelled::<i64>("Time:")?;
+        let distances = distances.labelled::<i64>("Distance:")?;
+
+        if times.len() != distances.len() {
+            bail!("{} times but {} distances", times.len(), distances.len());
+        }
+
+        let records = times
+            .into_iter()
+            .zip(distances)
+            .map(|(time, distance)| Record { time, distance })
             .collect();
 
-        Ok(Self { records: records? })
+        Ok(Self { records })
     }
 }
 
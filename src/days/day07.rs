@@ -6,14 +6,29 @@ use std::{
     str::FromStr,
 };
 
-use advent::io;
+use crate::solution::Solution;
 use anyhow::{anyhow, Result};
 
-fn main() -> Result<()> {
-    let input = io::for_day(7)?;
-    println!("{}", part1(&input)?);
-    println!("{}", part2(&input)?);
-    Ok(())
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: usize = 7;
+
+    type Input = String;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        part2(input)
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Hash)]
@@ -310,6 +325,7 @@ fn part2(input: &str) -> Result<i32> {
 mod tests {
     use super::Rank::*;
     use super::*;
+    use crate::{examples, solution::Part};
 
     #[test]
     fn test_hand_from_str() -> Result<()> {
@@ -394,4 +410,14 @@ QQQJA 483";
         assert_eq!(part2(EXAMPLE_INPUT)?, 5905);
         Ok(())
     }
+
+    #[test]
+    fn test_examples() -> Result<()> {
+        let checks = examples::verify(&Day07, &Part::values())?;
+        assert!(!checks.is_empty(), "no examples saved");
+        for check in checks {
+            assert_eq!(check.actual, check.expected, "part {}", check.part);
+        }
+        Ok(())
+    }
 }
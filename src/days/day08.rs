@@ -0,0 +1,311 @@
+use std::{collections::HashMap, str::FromStr};
+
+use crate::{cycle, geom::Turn, math, search, solution::Solution};
+use anyhow::{anyhow, bail, Result};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: usize = 8;
+
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        part2(input)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Instruction {
+    directions: Vec<Turn>,
+    map: HashMap<String, (String, String)>,
+}
+
+impl FromStr for Instruction {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let parts = s.split("\n\n").collect::<Vec<_>>();
+        let [directions, map] = parts.as_slice() else {
+            bail!("Invalid input {}", s);
+        };
+
+        let directions = directions
+            .chars()
+            .map(|c| match c {
+                'R' => Ok(Turn::Right),
+                'L' => Ok(Turn::Left),
+                _ => bail!("Invalid input {}", s),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        if directions.is_empty() {
+            bail!("No directions given");
+        }
+
+        // AAA = (BBB, CCC)
+        let map = map
+            .lines()
+            .map(|l| {
+                let parts = l.split(" = ").collect::<Vec<_>>();
+                let [key, value] = parts.as_slice() else {
+                    bail!("Invalid input {}", s);
+                };
+
+                let value = value.to_string().replace(['(', ')'], "");
+                let parts = value.split(", ").collect::<Vec<_>>();
+
+                let [left, right] = parts.as_slice() else {
+                    bail!("Invalid input {}", s);
+                };
+
+                Ok((key.to_string(), (left.to_string(), right.to_string())))
+            })
+            .collect::<Result<HashMap<_, _>>>()?;
+
+        Ok(Self { directions, map })
+    }
+}
+
+fn walk(instructions: Instruction) -> Option<usize> {
+    let directions = instructions.directions.len();
+
+    // where we are in the directions matters as much as which node we're on
+    search::bfs(
+        ("AAA", 0),
+        |&(node, step)| {
+            let (left, right) = instructions.map.get(node)?;
+            let next = match instructions.directions[step] {
+                Turn::Right => right,
+                Turn::Left => left,
+            };
+            Some((next.as_str(), (step + 1) % directions))
+        },
+        |&(node, _)| node == "ZZZ",
+    )
+    .cost()
+}
+
+fn ghost_walk(instructions: Instruction) -> Result<usize> {
+    if let Some(missing) = instructions
+        .map
+        .values()
+        .flat_map(|(left, right)| [left, right])
+        .find(|node| !instructions.map.contains_key(*node))
+    {
+        bail!("{} is never defined", missing);
+    }
+
+    let directions = instructions.directions.len();
+    let step = |&(node, step): &(&str, usize)| {
+        let (left, right) = &instructions.map[node];
+        let next = match instructions.directions[step] {
+            Turn::Right => right,
+            Turn::Left => left,
+        };
+        (next.as_str(), (step + 1) % directions)
+    };
+
+    let ghosts = instructions
+        .map
+        .keys()
+        .filter(|s| s.ends_with('A'))
+        .map(|start| {
+            let history = cycle::find((start.as_str(), 0), step);
+            let finishes = history
+                .states
+                .iter()
+                .enumerate()
+                .filter(|(_, (node, _))| node.ends_with('Z'))
+                .map(|(i, _)| i)
+                .collect::<Vec<_>>();
+            (history, finishes)
+        })
+        .collect::<Vec<_>>();
+    if ghosts.is_empty() {
+        bail!("no ghosts start on an A node");
+    }
+
+    let all_finished = |n: usize| {
+        ghosts
+            .iter()
+            .all(|(history, finishes)| finishes.contains(&history.cycle.reduce(n)))
+    };
+
+    // until every ghost is going round its loop, just check each step
+    let settled = ghosts
+        .iter()
+        .map(|(history, _)| history.cycle.prefix)
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    if let Some(n) = (1..settled).find(|&n| all_finished(n)) {
+        return Ok(n);
+    }
+
+    // after that, each ghost finishes at a fixed offset into its loop, so
+    // line up every combination of offsets and take the earliest
+    let mut solutions = vec![(0, 1)];
+    for (history, finishes) in &ghosts {
+        let length = history.cycle.length as i64;
+        solutions = solutions
+            .iter()
+            .flat_map(|&solution| {
+                finishes
+                    .iter()
+                    .filter(|&&i| i >= history.cycle.prefix)
+                    .flat_map(move |&i| math::crt([solution, (i as i64 % length, length)]))
+            })
+            .collect();
+    }
+
+    let settled = settled as i64;
+    solutions
+        .into_iter()
+        .map(|(x, period)| {
+            if x >= settled {
+                x
+            } else {
+                x + (settled - x + period - 1) / period * period
+            }
+        })
+        .min()
+        .map(|n| n as usize)
+        .ok_or(anyhow!("the ghosts never all finish at the same time"))
+}
+
+fn part1(input: &str) -> Result<usize> {
+    let instructions = Instruction::from_str(input)?;
+    walk(instructions).ok_or(anyhow!("AAA never reaches ZZZ"))
+}
+
+fn part2(input: &str) -> Result<usize> {
+    let instructions = Instruction::from_str(input)?;
+    ghost_walk(instructions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_INPUT: &str = "RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)";
+
+    #[test]
+    fn test_from_str() -> Result<()> {
+        let expected = Instruction {
+            directions: vec![Turn::Right, Turn::Left],
+            map: vec![
+                ("AAA".to_string(), ("BBB".to_string(), "CCC".to_string())),
+                ("BBB".to_string(), ("DDD".to_string(), "EEE".to_string())),
+                ("CCC".to_string(), ("ZZZ".to_string(), "GGG".to_string())),
+                ("DDD".to_string(), ("DDD".to_string(), "DDD".to_string())),
+                ("EEE".to_string(), ("EEE".to_string(), "EEE".to_string())),
+                ("GGG".to_string(), ("GGG".to_string(), "GGG".to_string())),
+                ("ZZZ".to_string(), ("ZZZ".to_string(), "ZZZ".to_string())),
+            ]
+            .into_iter()
+            .collect(),
+        };
+
+        assert_eq!(Instruction::from_str(EXAMPLE_INPUT)?, expected);
+
+        let error = Instruction::from_str("\n\nAAA = (ZZZ, ZZZ)").unwrap_err();
+        assert_eq!(error.to_string(), "No directions given");
+        Ok(())
+    }
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        let example_input = "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+
+        assert_eq!(part1(example_input)?, 6);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part1_unreachable() {
+        let example_input = "L
+
+AAA = (BBB, ZZZ)
+BBB = (AAA, AAA)
+ZZZ = (ZZZ, ZZZ)";
+
+        assert!(part1(example_input).is_err());
+    }
+
+    #[test]
+    fn test_part2_offsets() -> Result<()> {
+        // one ghost finishes after 1 step and then every 2 steps, the other
+        // takes a step to reach a loop of 3 that finishes at its end
+        let example_input = "L
+
+11A = (11Z, 11Z)
+11Z = (11B, 11B)
+11B = (11Z, 11Z)
+22A = (22B, 22B)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)";
+
+        assert_eq!(part2(example_input)?, 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2_never() {
+        // the ghosts are always on opposite sides of their loops
+        let example_input = "L
+
+11A = (11Z, 11Z)
+11Z = (11A, 11A)
+22A = (22B, 22B)
+22B = (22Z, 22Z)
+22Z = (22B, 22B)";
+
+        assert!(part2(example_input).is_err());
+    }
+
+    #[test]
+    fn test_part2_no_ghosts() {
+        let error = part2("L\n\nBBB = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap_err();
+        assert_eq!(error.to_string(), "no ghosts start on an A node");
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        let example_input = "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+
+        assert_eq!(part2(example_input)?, 6);
+        Ok(())
+    }
+}
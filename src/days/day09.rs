@@ -0,0 +1,217 @@
+use crate::solution::Solution;
+use anyhow::{anyhow, bail, Result};
+use num::{BigInt, ToPrimitive, Zero};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: usize = 9;
+
+    type Input = String;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        part2(input)
+    }
+}
+
+fn part1(input: &str) -> Result<i64> {
+    let next_values = input
+        .lines()
+        .map(|l| {
+            let sequence = parse(l)?;
+            Polynomial::fit(&sequence)?.at(sequence.len() as i64)
+        })
+        .collect::<Result<Vec<i64>>>()?;
+
+    Ok(next_values.iter().sum())
+}
+
+fn part2(input: &str) -> Result<i64> {
+    let next_values = input
+        .lines()
+        .map(|l| {
+            let sequence = parse(l)?;
+            Polynomial::fit(&sequence)?.at(-1)
+        })
+        .collect::<Result<Vec<i64>>>()?;
+
+    Ok(next_values.iter().sum())
+}
+
+fn differences(sequence: &[BigInt]) -> Vec<BigInt> {
+    sequence
+        .windows(2)
+        .map(|pair| &pair[1] - &pair[0])
+        .collect()
+}
+
+fn parse(input: &str) -> Result<Vec<i64>> {
+    input
+        .split_ascii_whitespace()
+        .map(|s: &str| Ok(s.parse::<i64>()?))
+        .collect::<Result<Vec<i64>>>()
+}
+
+/// The polynomial that passes through `sequence[x]` at each `x`, stored as
+/// the first value of each row of differences so it stays exact.
+#[derive(Debug, PartialEq)]
+pub struct Polynomial {
+    leading: Vec<BigInt>,
+}
+
+impl Polynomial {
+    /// Fails unless the differences reach all zeros before running out of
+    /// values, since otherwise the sequence is too short to pin the degree down.
+    pub fn fit(sequence: &[i64]) -> Result<Self> {
+        let mut leading = Vec::new();
+        let mut row = sequence
+            .iter()
+            .map(|&x| BigInt::from(x))
+            .collect::<Vec<_>>();
+
+        while row.iter().any(|d| !d.is_zero()) {
+            if row.len() == 1 {
+                bail!(
+                    "{:?} doesn't settle into a polynomial of degree below {}",
+                    sequence,
+                    sequence.len() - 1
+                );
+            }
+            leading.push(row[0].clone());
+            row = differences(&row);
+        }
+
+        if row.is_empty() {
+            bail!("can't fit a polynomial to an empty sequence");
+        }
+
+        Ok(Self { leading })
+    }
+
+    /// The highest power of `x`, counting all zeros as degree 0.
+    pub fn degree(&self) -> usize {
+        self.leading.len().saturating_sub(1)
+    }
+
+    /// Newton's forward difference formula, `sum(Δᵏ * C(x, k))`, which is
+    /// exact for any `x`, including ones before the start.
+    pub fn at(&self, x: i64) -> Result<i64> {
+        let x = BigInt::from(x);
+        let mut binomial = BigInt::from(1);
+        let mut sum = BigInt::zero();
+
+        for (k, difference) in self.leading.iter().enumerate() {
+            if k > 0 {
+                // C(x, k) = C(x, k - 1) * (x - k + 1) / k, which always divides exactly
+                binomial = binomial * (&x - (k - 1)) / k;
+            }
+            sum += &binomial * difference;
+        }
+
+        sum.to_i64().ok_or(anyhow!(
+            "the degree {} polynomial is {} at {}, which doesn't fit in an i64",
+            self.degree(),
+            sum,
+            x
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{examples, solution::Part};
+
+    #[test]
+    fn test_differences() {
+        let input = [1, 2, 3, 4, 5].map(BigInt::from);
+        let expected_output = vec![BigInt::from(1); 4];
+
+        assert_eq!(differences(&input), expected_output);
+    }
+
+    #[test]
+    fn test_differences_are_exact() -> Result<()> {
+        // the first difference alone is past i64::MAX
+        let polynomial = Polynomial::fit(&[i64::MIN, i64::MAX, i64::MAX, i64::MIN])?;
+        assert_eq!(polynomial.degree(), 2);
+        assert_eq!(polynomial.at(1)?, i64::MAX);
+        assert_eq!(polynomial.at(3)?, i64::MIN);
+        assert!(polynomial
+            .at(4)
+            .unwrap_err()
+            .to_string()
+            .starts_with("the degree 2 polynomial is "));
+        Ok(())
+    }
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        let example_input = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+        let expected_output = 114;
+
+        assert_eq!(part1(example_input)?, expected_output);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        let example_input = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+        let expected_output = 2;
+
+        assert_eq!(part2(example_input)?, expected_output);
+        Ok(())
+    }
+
+    #[test]
+    fn test_polynomial() -> Result<()> {
+        let polynomial = Polynomial::fit(&[10, 13, 16, 21, 30, 45])?;
+        assert_eq!(polynomial.degree(), 3);
+        assert_eq!(polynomial.at(6)?, 68);
+        assert_eq!(polynomial.at(-1)?, 5);
+
+        assert_eq!(Polynomial::fit(&[7, 7, 7])?.degree(), 0);
+        assert_eq!(Polynomial::fit(&[0, 0])?.at(100)?, 0);
+
+        assert!(Polynomial::fit(&[1, 2, 4, 8, 16, 32]).is_err());
+        assert!(Polynomial::fit(&[]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_polynomial_is_exact() -> Result<()> {
+        // x^12 gets far enough past 2^53 that an f64 would start rounding
+        let sequence = (0..20).map(|x: i64| x.pow(12)).collect::<Vec<_>>();
+        let polynomial = Polynomial::fit(&sequence)?;
+
+        assert_eq!(polynomial.degree(), 12);
+        assert_eq!(polynomial.at(20)?, 20i64.pow(12));
+        assert_eq!(polynomial.at(-25)?, 25i64.pow(12));
+        assert!(polynomial.at(1_000_000).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_examples() -> Result<()> {
+        let checks = examples::verify(&Day09, &Part::values())?;
+        assert!(!checks.is_empty(), "no examples saved");
+        for check in checks {
+            assert_eq!(check.actual, check.expected, "part {}", check.part);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,353 @@
+use crate::{
+    geom::{Direction4, Point2},
+    grid::Grid,
+    render::{Colour, Format, Renderer, Style},
+    search,
+    solution::Solution,
+};
+use anyhow::{anyhow, Result};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: usize = 10;
+
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        part2(input)
+    }
+}
+
+fn part1(input: &str) -> Result<usize> {
+    let grid = input.parse::<Grid<char>>()?;
+    let path = cycle(&grid, start(&grid)?).ok_or(anyhow!("No cycle found"))?;
+    Ok(path.len() / 2)
+}
+
+fn part2(input: &str) -> Result<usize> {
+    let grid = input.parse::<Grid<char>>()?;
+    let path = cycle(&grid, start(&grid)?).ok_or(anyhow!("No cycle found"))?;
+    let mut count = 0;
+    for y in 0..grid.height() {
+        for x in 0..grid.width() {
+            if point_in_polygon(&grid, Point2::new(x, y), &path) {
+                count += 1;
+            }
+        }
+    }
+    Ok(count)
+}
+
+fn start(grid: &Grid<char>) -> Result<Point2<usize>> {
+    let (y, x) = grid.find(&'S').ok_or(anyhow!("No start found"))?;
+    Ok(Point2::new(x, y))
+}
+
+fn von_neumann_neighbors(grid: &Grid<char>, point: Point2<usize>) -> Vec<(Direction4, &char)> {
+    Direction4::ALL
+        .iter()
+        .flat_map(|&dir| Some((dir, grid.get_point(point.checked_step(dir)?)?)))
+        .collect::<Vec<(Direction4, &char)>>()
+}
+
+fn valid_neighbor(grid: &Grid<char>, point: Point2<usize>, direction: &Direction4) -> bool {
+    let Some(current) = grid.get_point(point) else {
+        return false;
+    };
+
+    let Some(neighbor) = point
+        .checked_step(*direction)
+        .and_then(|next| grid.get_point(next))
+    else {
+        return false;
+    };
+
+    match (current, direction) {
+        ('S', Direction4::Up) => matches!(neighbor, '|' | 'F' | '7'),
+        ('S', Direction4::Down) => matches!(neighbor, '|' | 'J' | 'L'),
+        ('S', Direction4::Left) => matches!(neighbor, '-' | 'F' | 'L'),
+        ('S', Direction4::Right) => matches!(neighbor, '-' | 'J' | '7'),
+
+        ('|', Direction4::Up) => matches!(neighbor, '|' | 'F' | '7' | 'S'),
+        ('|', Direction4::Down) => matches!(neighbor, '|' | 'J' | 'L' | 'S'),
+
+        ('-', Direction4::Left) => matches!(neighbor, '-' | 'F' | 'L' | 'S'),
+        ('-', Direction4::Right) => matches!(neighbor, '-' | 'J' | '7' | 'S'),
+
+        ('F', Direction4::Right) => matches!(neighbor, '-' | 'J' | '7' | 'S'),
+        ('F', Direction4::Down) => matches!(neighbor, '|' | 'J' | 'L' | 'S'),
+
+        ('7', Direction4::Left) => matches!(neighbor, '-' | 'F' | 'L' | 'S'),
+        ('7', Direction4::Down) => matches!(neighbor, '|' | 'J' | 'L' | 'S'),
+
+        ('J', Direction4::Up) => matches!(neighbor, '|' | 'F' | '7' | 'S'),
+        ('J', Direction4::Left) => matches!(neighbor, '-' | 'F' | 'L' | 'S'),
+
+        ('L', Direction4::Up) => matches!(neighbor, '|' | 'F' | '7' | 'S'),
+        ('L', Direction4::Right) => matches!(neighbor, '-' | 'J' | '7' | 'S'),
+
+        _ => false,
+    }
+}
+
+fn valid_neighbors(grid: &Grid<char>, point: Point2<usize>) -> Vec<Point2<usize>> {
+    von_neumann_neighbors(grid, point)
+        .iter()
+        .filter(|(dir, _)| valid_neighbor(grid, point, dir))
+        .flat_map(|(dir, _)| point.checked_step(*dir))
+        .collect()
+}
+
+fn cycle(grid: &Grid<char>, start: Point2<usize>) -> Option<Vec<Point2<usize>>> {
+    // walk the loop one way, from the first pipe leaving the start to the
+    // other one, without cutting back through the start
+    let ends = valid_neighbors(grid, start);
+    let first = *ends.first()?;
+    let search = search::bfs(
+        first,
+        |&point| {
+            valid_neighbors(grid, point)
+                .into_iter()
+                .filter(|&neighbor| neighbor != start)
+        },
+        |point| *point != first && ends.contains(point),
+    );
+
+    let mut path = vec![start];
+    path.extend(search.path()?);
+    path.push(start);
+    Some(path)
+}
+
+fn point_in_polygon<T>(grid: &Grid<T>, point: Point2<usize>, path: &[Point2<usize>]) -> bool {
+    if path.contains(&point) {
+        return false; // only want points strictly inside
+    }
+
+    let mut crossings = 0;
+    let mut point = Some(point);
+
+    while let Some(current) = point {
+        if path.contains(&current) {
+            crossings += 1;
+        }
+
+        point = current
+            .checked_step(Direction4::Up)
+            .filter(|&next| grid.get_point(next).is_some());
+    }
+
+    crossings % 2 == 1
+}
+
+/// Draws the pipes with the loop through the start picked out, for debugging
+/// which tiles end up inside it.
+pub fn render(input: &str, format: Format, scale: usize) -> Result<Vec<u8>> {
+    let grid = input.parse::<Grid<char>>()?;
+    let renderer = renderer(&grid).ok_or(anyhow!("No cycle found"))?;
+    Ok(renderer.to_bytes(format, scale))
+}
+
+fn renderer(grid: &Grid<char>) -> Option<Renderer<'_, char>> {
+    let path = cycle(grid, start(grid).ok()?)?;
+
+    Some(
+        Renderer::new(grid, |&c| {
+            let glyph = match c {
+                '|' => '│',
+                '-' => '─',
+                'L' => '└',
+                'J' => '┘',
+                '7' => '┐',
+                'F' => '┌',
+                _ => c,
+            };
+            Style::new(glyph).with_foreground(Colour::GREY)
+        })
+        .with_path(path, Colour::BLUE),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Direction4::*;
+    use super::*;
+
+    const EXAMPLE_INPUT: &str = "7-F7-
+.FJ|7
+SJLL7
+|F--J
+LJ.LJ";
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let parsed = EXAMPLE_INPUT.parse::<Grid<char>>()?;
+        assert_eq!(
+            parsed,
+            Grid::new(
+                5,
+                5,
+                vec![
+                    '7', '-', 'F', '7', '-', '.', 'F', 'J', '|', '7', 'S', 'J', 'L', 'L', '7', '|',
+                    'F', '-', '-', 'J', 'L', 'J', '.', 'L', 'J',
+                ],
+            )?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_get() -> Result<()> {
+        let parsed = EXAMPLE_INPUT.parse::<Grid<char>>()?;
+        assert_eq!(parsed.find(&'S'), Some((2, 0)));
+        assert_eq!(parsed.get(2, 0), Some(&'S'));
+        Ok(())
+    }
+
+    #[test]
+    fn test_von_neumann_neighborhood() -> Result<()> {
+        let parsed = EXAMPLE_INPUT.parse::<Grid<char>>()?;
+        assert_eq!(
+            von_neumann_neighbors(&parsed, Point2::new(0, 0)),
+            vec![(Right, &'-'), (Down, &'.')]
+        );
+        assert_eq!(
+            von_neumann_neighbors(&parsed, Point2::new(1, 1)),
+            vec![(Up, &'-'), (Right, &'J'), (Down, &'J'), (Left, &'.')]
+        );
+        assert_eq!(
+            von_neumann_neighbors(&parsed, Point2::new(4, 4)),
+            vec![(Up, &'J'), (Left, &'L')]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_valid_neighbor() -> Result<()> {
+        let grid = EXAMPLE_INPUT.parse::<Grid<char>>()?;
+        let start = start(&grid)?;
+
+        assert!(!valid_neighbor(&grid, start, &Up));
+        assert!(valid_neighbor(&grid, start, &Right));
+        assert!(valid_neighbor(&grid, start, &Down));
+        assert!(!valid_neighbor(&grid, start, &Left));
+
+        let below = start.checked_step(Down).unwrap();
+        assert!(valid_neighbor(&grid, below, &Up));
+
+        let right = start.checked_step(Right).unwrap();
+        assert!(valid_neighbor(&grid, right, &Left));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cycle_length() -> Result<()> {
+        let grid = EXAMPLE_INPUT.parse::<Grid<char>>()?;
+        let start = start(&grid)?;
+
+        assert_eq!(
+            cycle(&grid, start),
+            Some(vec![
+                Point2::new(0, 2),
+                Point2::new(1, 2),
+                Point2::new(1, 1),
+                Point2::new(2, 1),
+                Point2::new(2, 0),
+                Point2::new(3, 0),
+                Point2::new(3, 1),
+                Point2::new(3, 2),
+                Point2::new(4, 2),
+                Point2::new(4, 3),
+                Point2::new(3, 3),
+                Point2::new(2, 3),
+                Point2::new(1, 3),
+                Point2::new(1, 4),
+                Point2::new(0, 4),
+                Point2::new(0, 3),
+                Point2::new(0, 2),
+            ])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    #[ignore]
+    fn test_point_in_polygon() -> Result<()> {
+        let grid = "...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+..........."
+            .parse::<Grid<char>>()?;
+        let path = cycle(&grid, start(&grid)?).unwrap();
+
+        assert!(point_in_polygon(&grid, Point2::new(2, 6), &path));
+        assert!(point_in_polygon(&grid, Point2::new(3, 6), &path));
+        assert!(point_in_polygon(&grid, Point2::new(7, 6), &path));
+        assert!(point_in_polygon(&grid, Point2::new(8, 6), &path));
+        assert!(!point_in_polygon(&grid, Point2::new(3, 3), &path));
+        assert!(!point_in_polygon(&grid, Point2::new(1, 8), &path)); // todo handle edges, maybe try winding number?
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render() -> Result<()> {
+        let grid = EXAMPLE_INPUT.parse::<Grid<char>>()?;
+        let svg = renderer(&grid).unwrap().to_svg(10);
+
+        assert_eq!(svg.matches("<rect").count(), 16);
+        assert!(svg.contains("<polyline points=\"5,25 15,25"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        let expected_output = 8;
+
+        assert_eq!(part1(EXAMPLE_INPUT)?, expected_output);
+        Ok(())
+    }
+
+    #[test]
+    fn test_no_start() {
+        let error = part1("F7\nLJ").unwrap_err();
+        assert_eq!(error.to_string(), "No start found");
+    }
+
+    #[test]
+    #[ignore]
+    fn test_part2() -> Result<()> {
+        let input = "FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L";
+
+        assert_eq!(part2(input)?, 10);
+        Ok(())
+    }
+}
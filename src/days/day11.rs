@@ -1,13 +1,28 @@
 use std::collections::HashSet;
 
-use advent::io;
+use crate::{geom::Point2, solution::Solution};
 use anyhow::Result;
 
-fn main() -> Result<()> {
-    let input = io::for_day(11)?;
-    println!("{}", part1(&input)?);
-    println!("{}", part2(&input)?);
-    Ok(())
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: usize = 11;
+
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        part2(input)
+    }
 }
 
 fn part1(input: &str) -> Result<usize> {
@@ -50,9 +65,8 @@ fn calculate_distances(input: &str, expansion_factor: usize) -> Result<usize> {
 
     for i in 0..points.len() {
         let p1 = points[i];
-        for j in i + 1..points.len() {
-            let p2 = points[j];
-            total += manhattan_distance(p1.0 as isize, p1.1 as isize, p2.0 as isize, p2.1 as isize);
+        for &p2 in points.iter().skip(i + 1) {
+            total += Point2::new(p1.1, p1.0).manhattan(&Point2::new(p2.1, p2.0));
         }
     }
 
@@ -72,10 +86,6 @@ fn expand(
     }
 }
 
-fn manhattan_distance(x1: isize, y1: isize, x2: isize, y2: isize) -> usize {
-    ((x1 - x2).abs() + (y1 - y2).abs()) as usize
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
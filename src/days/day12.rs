@@ -0,0 +1,319 @@
+use crate::{parse::Text, solution::Solution};
+use anyhow::{anyhow, bail, Error, Result};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: usize = 12;
+
+    type Input = Vec<Record>;
+    type Part1 = u128;
+    type Part2 = u128;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Text::new(input).lines().map(Record::parse).collect()
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        part2(input)
+    }
+}
+
+/// How many copies of each record part 2 joins together.
+pub const UNFOLD: usize = 5;
+
+fn part1(records: &[Record]) -> Result<u128> {
+    total_unfolded(records, 1)
+}
+
+fn part2(records: &[Record]) -> Result<u128> {
+    total_unfolded(records, UNFOLD)
+}
+
+/// The arrangements of every record once unfolded `factor` times, where a
+/// factor of 1 leaves them as they are.
+pub fn total_unfolded(records: &[Record], factor: usize) -> Result<u128> {
+    records.iter().try_fold(0u128, |total, record| {
+        total
+            .checked_add(count_arrangements(&record.unfold(factor))?)
+            .ok_or(anyhow!("the total is more than {}", u128::MAX))
+    })
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Spring {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+impl TryFrom<char> for Spring {
+    type Error = Error;
+
+    fn try_from(c: char) -> Result<Self> {
+        match c {
+            '.' => Ok(Spring::Operational),
+            '#' => Ok(Spring::Damaged),
+            '?' => Ok(Spring::Unknown),
+            _ => bail!("Unknown spring: {}", c),
+        }
+    }
+}
+
+/// One row of springs, and the sizes of each run of damaged ones in order.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Record {
+    springs: Vec<Spring>,
+    groups: Vec<usize>,
+}
+
+impl Record {
+    fn parse(line: Text) -> Result<Self> {
+        let (springs, groups) = line.trim().split_once(" ")?;
+
+        Ok(Record {
+            springs: springs.chars()?,
+            groups: groups
+                .split(",")
+                .map(|group| match group.parse()? {
+                    0 => Err(group.trim().error("groups can't be empty")),
+                    size => Ok(size),
+                })
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    /// `factor` copies of the springs joined by unknowns, and of the groups.
+    pub fn unfold(&self, factor: usize) -> Self {
+        let mut springs = Vec::with_capacity((self.springs.len() + 1) * factor);
+        for i in 0..factor {
+            if i > 0 {
+                springs.push(Spring::Unknown);
+            }
+            springs.extend(&self.springs);
+        }
+
+        Record {
+            springs,
+            groups: self.groups.repeat(factor),
+        }
+    }
+}
+
+/// How many ways the unknown springs can be filled in to give the groups,
+/// failing if there are more than fit in a `u128`.
+pub fn count_arrangements(record: &Record) -> Result<u128> {
+    let Record { springs, groups } = record;
+    let n = springs.len();
+
+    // how many springs before each position are known to be working, so a
+    // group can be checked for room in one go
+    let mut operational = vec![0; n + 1];
+    for (i, spring) in springs.iter().enumerate() {
+        operational[i + 1] = operational[i] + usize::from(*spring == Spring::Operational);
+    }
+
+    // ways[i][g] is how many ways springs[i..] can hold groups[g..], filled
+    // in from the end so every lookup is already known
+    let mut ways = vec![vec![0u128; groups.len() + 1]; n + 2];
+    ways[n][groups.len()] = 1;
+    ways[n + 1][groups.len()] = 1;
+
+    for i in (0..n).rev() {
+        for g in 0..=groups.len() {
+            let mut total = 0u128;
+
+            if springs[i] != Spring::Damaged {
+                total = ways[i + 1][g];
+            }
+
+            if springs[i] != Spring::Operational && g < groups.len() {
+                let end = i + groups[g];
+                let fits = end <= n
+                    && operational[end] == operational[i]
+                    && springs.get(end) != Some(&Spring::Damaged);
+                if fits {
+                    // skip the spring after the group too, it has to be working
+                    total = total
+                        .checked_add(ways[end + 1][g + 1])
+                        .ok_or(anyhow!("there are more than {} arrangements", u128::MAX))?;
+                }
+            }
+
+            ways[i][g] = total;
+        }
+    }
+
+    Ok(ways[0][0])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{examples, solution::Part};
+    use quickcheck::Arbitrary;
+
+    fn records(input: &str) -> Vec<Record> {
+        Day12.parse(input).unwrap()
+    }
+
+    fn example() -> Vec<Record> {
+        records(&examples::for_day(12).unwrap()[0].input)
+    }
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        use Spring::*;
+
+        assert_eq!(
+            Record::parse(Text::new("#.? 1,1"))?,
+            Record {
+                springs: vec![Damaged, Operational, Unknown],
+                groups: vec![1, 1],
+            }
+        );
+
+        let error = Day12.parse("???.### 1,1,3\n??x 1").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: Unknown spring: x in `x`"
+        );
+
+        let error = Day12.parse("???.### 1,1,3\n??? 1,0").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 7: groups can't be empty in `0`"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_unfold() -> Result<()> {
+        let record = Record::parse(Text::new(".# 1"))?;
+        assert_eq!(
+            record.unfold(5),
+            Record::parse(Text::new(".#?.#?.#?.#?.# 1,1,1,1,1"))?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_count_arrangements() -> Result<()> {
+        let counts = example()
+            .iter()
+            .map(count_arrangements)
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(counts, vec![1, 4, 1, 1, 4, 10]);
+
+        let unfolded = example()
+            .iter()
+            .map(|record| count_arrangements(&record.unfold(UNFOLD)))
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(unfolded, vec![1, 16384, 1, 16, 2500, 506250]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_count_arrangements_edge_cases() -> Result<()> {
+        assert_eq!(count_arrangements(&records("... 1")[0])?, 0);
+        assert_eq!(count_arrangements(&records("### 3")[0])?, 1);
+        assert_eq!(count_arrangements(&records("#?# 3")[0])?, 1);
+        assert_eq!(count_arrangements(&records("#?# 1,1")[0])?, 1);
+        assert_eq!(count_arrangements(&records("#?# 1")[0])?, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_larger_unfold() -> Result<()> {
+        // every extra copy of this one adds an unknown that can join either
+        // neighbour, giving another factor of 8
+        let branching = records(".??..??...?##. 1,1,3");
+        assert_eq!(total_unfolded(&branching, 8)?, 4 * 8u128.pow(7));
+        assert_eq!(total_unfolded(&branching, 40)?, 4 * 8u128.pow(39));
+
+        // and this one is forced however far it is unfolded
+        let forced = records("???.### 1,1,3");
+        assert_eq!(total_unfolded(&forced, 50)?, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_overflow() {
+        // 210 choose 70 ways to place the groups, which is well past u128::MAX
+        let error = total_unfolded(&records("??? 1"), 70).unwrap_err();
+        assert!(error.to_string().starts_with("there are more than "));
+    }
+
+    // tries every way of filling in the unknowns
+    fn brute_force(record: &Record) -> usize {
+        let unknowns = record
+            .springs
+            .iter()
+            .filter(|&&spring| spring == Spring::Unknown)
+            .count();
+
+        (0..1usize << unknowns)
+            .filter(|mask| {
+                let mut bit = 0;
+                let mut groups = Vec::new();
+                let mut run = 0;
+                for &spring in &record.springs {
+                    let damaged = match spring {
+                        Spring::Unknown => {
+                            bit += 1;
+                            mask >> (bit - 1) & 1 == 1
+                        }
+                        spring => spring == Spring::Damaged,
+                    };
+                    if damaged {
+                        run += 1;
+                    } else if run > 0 {
+                        groups.push(run);
+                        run = 0;
+                    }
+                }
+                if run > 0 {
+                    groups.push(run);
+                }
+                groups == record.groups
+            })
+            .count()
+    }
+
+    #[derive(Debug, Clone)]
+    struct SmallRecord(Record);
+
+    impl Arbitrary for SmallRecord {
+        fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+            let springs = (0..usize::arbitrary(g) % 12)
+                .map(|_| {
+                    *g.choose(&[Spring::Operational, Spring::Damaged, Spring::Unknown])
+                        .unwrap()
+                })
+                .collect();
+            let groups = (0..usize::arbitrary(g) % 4)
+                .map(|_| usize::arbitrary(g) % 3 + 1)
+                .collect();
+            SmallRecord(Record { springs, groups })
+        }
+    }
+
+    #[quickcheck_macros::quickcheck]
+    fn test_matches_brute_force(record: SmallRecord) -> bool {
+        count_arrangements(&record.0).unwrap() == brute_force(&record.0) as u128
+    }
+
+    #[test]
+    fn test_examples() -> Result<()> {
+        let checks = examples::verify(&Day12, &Part::values())?;
+        assert!(!checks.is_empty(), "no examples saved");
+        for check in checks {
+            assert_eq!(check.actual, check.expected, "part {}", check.part);
+        }
+        Ok(())
+    }
+}
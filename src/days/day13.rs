@@ -0,0 +1,245 @@
+use crate::{grid::Grid, parse::Text, solution::Solution};
+use anyhow::{anyhow, Result};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: usize = 13;
+
+    type Input = Vec<Grid<char>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(Text::new(input)
+            .sections()
+            .map(|section| section.grid())
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        part2(input)
+    }
+}
+
+fn part1(patterns: &[Grid<char>]) -> Result<usize> {
+    summarise(patterns, 0)
+}
+
+fn part2(patterns: &[Grid<char>]) -> Result<usize> {
+    summarise(patterns, 1)
+}
+
+fn summarise(patterns: &[Grid<char>], smudges: usize) -> Result<usize> {
+    Ok(reflections(patterns, smudges)?
+        .iter()
+        .map(Reflection::score)
+        .sum())
+}
+
+/// The mirror in each pattern that is off by exactly `smudges` cells, in order.
+pub fn reflections<T: PartialEq>(patterns: &[Grid<T>], smudges: usize) -> Result<Vec<Reflection>> {
+    patterns
+        .iter()
+        .enumerate()
+        .map(|(i, pattern)| {
+            find_reflection(pattern, smudges).ok_or(anyhow!(
+                "pattern {} has no reflection with {} smudges",
+                i + 1,
+                smudges
+            ))
+        })
+        .collect()
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Orientation {
+    /// A mirror lying between two rows.
+    Horizontal,
+    /// A mirror standing between two columns.
+    Vertical,
+}
+
+/// Where a pattern's mirror is, with `axis` the number of rows above it or
+/// columns to its left.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Reflection {
+    pub orientation: Orientation,
+    pub axis: usize,
+}
+
+impl Reflection {
+    /// Columns to the left, plus 100 for every row above.
+    pub fn score(&self) -> usize {
+        match self.orientation {
+            Orientation::Horizontal => 100 * self.axis,
+            Orientation::Vertical => self.axis,
+        }
+    }
+}
+
+/// The first mirror, checking between rows before between columns, that is
+/// off by exactly `smudges` cells.
+pub fn find_reflection<T: PartialEq>(grid: &Grid<T>, smudges: usize) -> Option<Reflection> {
+    [Orientation::Horizontal, Orientation::Vertical]
+        .into_iter()
+        .find_map(|orientation| {
+            reflection(grid, orientation, smudges).map(|axis| Reflection { orientation, axis })
+        })
+}
+
+fn reflection<T: PartialEq>(
+    grid: &Grid<T>,
+    orientation: Orientation,
+    smudges: usize,
+) -> Option<usize> {
+    let length = match orientation {
+        Orientation::Horizontal => grid.height(),
+        Orientation::Vertical => grid.width(),
+    };
+    (1..length).find(|&axis| mismatches(grid, orientation, axis) == smudges)
+}
+
+/// How many cells differ from their mirror image in a mirror at `axis`,
+/// ignoring whatever hangs off the edge on the longer side.
+fn mismatches<T: PartialEq>(grid: &Grid<T>, orientation: Orientation, axis: usize) -> usize {
+    let (length, breadth) = match orientation {
+        Orientation::Horizontal => (grid.height(), grid.width()),
+        Orientation::Vertical => (grid.width(), grid.height()),
+    };
+    let cell = |along: usize, across: usize| match orientation {
+        Orientation::Horizontal => grid.get(along, across),
+        Orientation::Vertical => grid.get(across, along),
+    };
+
+    (0..axis.min(length - axis))
+        .flat_map(|offset| (0..breadth).map(move |across| (offset, across)))
+        .filter(|&(offset, across)| cell(axis - offset - 1, across) != cell(axis + offset, across))
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{examples, solution::Part};
+
+    fn patterns() -> Vec<Grid<char>> {
+        Day13
+            .parse(&examples::for_day(13).unwrap()[0].input)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_grid() {
+        let grid = &patterns()[0];
+
+        assert_eq!(
+            grid.row(0),
+            Some(&['#', '.', '#', '#', '.', '.', '#', '#', '.'][..])
+        );
+
+        assert_eq!(
+            grid.row(2),
+            Some(&['#', '#', '.', '.', '.', '.', '.', '.', '#'][..])
+        );
+
+        assert_eq!(grid.row(7), None);
+
+        let column = |c| grid.column(c).map(|cells| cells.collect::<String>());
+        assert_eq!(column(0), Some("#.##..#".to_string()));
+        assert_eq!(column(8), Some("..##...".to_string()));
+        assert_eq!(column(9), None);
+    }
+
+    #[test]
+    fn test_reflect_about_vertical() {
+        let expected_output = 5;
+        assert_eq!(
+            reflection(&patterns()[0], Orientation::Vertical, 0),
+            Some(expected_output)
+        );
+    }
+
+    #[test]
+    fn test_find_reflection() {
+        let patterns = patterns();
+
+        assert_eq!(
+            find_reflection(&patterns[0], 0),
+            Some(Reflection {
+                orientation: Orientation::Vertical,
+                axis: 5
+            })
+        );
+        assert_eq!(
+            find_reflection(&patterns[1], 0),
+            Some(Reflection {
+                orientation: Orientation::Horizontal,
+                axis: 4
+            })
+        );
+
+        // fixing one smudge moves both mirrors
+        assert_eq!(
+            find_reflection(&patterns[0], 1),
+            Some(Reflection {
+                orientation: Orientation::Horizontal,
+                axis: 3
+            })
+        );
+        assert_eq!(
+            find_reflection(&patterns[1], 1),
+            Some(Reflection {
+                orientation: Orientation::Horizontal,
+                axis: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_mismatches() {
+        let patterns = patterns();
+
+        assert_eq!(mismatches(&patterns[0], Orientation::Vertical, 5), 0);
+        assert_eq!(mismatches(&patterns[0], Orientation::Horizontal, 3), 1);
+        // only the one row on each side of the mirror is compared
+        assert_eq!(mismatches(&patterns[1], Orientation::Horizontal, 1), 1);
+        assert_eq!(find_reflection(&patterns[0], 100), None);
+    }
+
+    #[test]
+    fn test_reflections() -> Result<()> {
+        let scores = |smudges| -> Result<Vec<usize>> {
+            Ok(reflections(&patterns(), smudges)?
+                .iter()
+                .map(Reflection::score)
+                .collect())
+        };
+        assert_eq!(scores(0)?, vec![5, 400]);
+        assert_eq!(scores(1)?, vec![300, 100]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_examples() -> Result<()> {
+        let checks = examples::verify(&Day13, &Part::values())?;
+        assert!(!checks.is_empty(), "no examples saved");
+        for check in checks {
+            assert_eq!(check.actual, check.expected, "part {}", check.part);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_no_reflection() {
+        let pattern = Day13.parse("#.\n..").unwrap();
+        assert_eq!(
+            part1(&pattern).unwrap_err().to_string(),
+            "pattern 1 has no reflection with 0 smudges"
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use crate::{cycle, grid::Grid, parse::Text, solution::Solution};
+use anyhow::Result;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: usize = 14;
+
+    type Input = Grid<char>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(Text::new(input).grid()?)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        part2(input)
+    }
+}
+
+/// How many spin cycles part 2 runs the platform through.
+pub const CYCLES: usize = 1_000_000_000;
+
+fn part1(platform: &Grid<char>) -> Result<usize> {
+    let mut platform = platform.clone();
+    tilt_north(&mut platform);
+    Ok(north_load(&platform))
+}
+
+fn part2(platform: &Grid<char>) -> Result<usize> {
+    Ok(north_load(&spin_cycles(platform.clone(), CYCLES)))
+}
+
+fn tilt_north(grid: &mut Grid<char>) {
+    for col in 0..grid.width() {
+        // where the next rock rolling up this column comes to rest
+        let mut free = 0;
+        for row in 0..grid.height() {
+            match grid[(row, col)] {
+                '#' => free = row + 1,
+                'O' => {
+                    grid[(row, col)] = '.';
+                    grid[(free, col)] = 'O';
+                    free += 1;
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+// north, west, south then east, by always tilting north and turning the
+// platform so the next edge is at the top
+fn spin(grid: &Grid<char>) -> Grid<char> {
+    let mut grid = grid.clone();
+    for _ in 0..4 {
+        tilt_north(&mut grid);
+        grid = grid.rotate_clockwise();
+    }
+    grid
+}
+
+fn spin_cycles(grid: Grid<char>, cycles: usize) -> Grid<char> {
+    cycle::find(grid, spin).nth(cycles).clone()
+}
+
+fn north_load(grid: &Grid<char>) -> usize {
+    grid.cells()
+        .filter(|(_, &c)| c == 'O')
+        .map(|((row, _), _)| grid.height() - row)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{examples, solution::Part};
+
+    fn example() -> Grid<char> {
+        Day14
+            .parse(&examples::for_day(14).unwrap()[0].input)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_tilt_north() -> Result<()> {
+        let mut input = example();
+        let expected = Grid::<char>::from_str(
+            "OOOO.#.O..
+OO..#....#
+OO..O##..O
+O..#.OO...
+........#.
+..#....#.#
+..O..#.O.O
+..O.......
+#....###..
+#....#....",
+        )?;
+
+        tilt_north(&mut input);
+        assert_eq!(input, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_spin_cycles() -> Result<()> {
+        let input = example();
+        let expected = Grid::<char>::from_str(
+            ".....#....
+....#...O#
+...OO##...
+.OO#......
+.....OOO#.
+.O#...O#.#
+....O#....
+......OOOO
+#...O###..
+#..OO#....",
+        )?;
+
+        assert_eq!(spin(&input), expected);
+        assert_eq!(north_load(&spin_cycles(input, CYCLES)), 64);
+        Ok(())
+    }
+
+    #[test]
+    fn test_examples() -> Result<()> {
+        let checks = examples::verify(&Day14, &Part::values())?;
+        assert!(!checks.is_empty(), "no examples saved");
+        for check in checks {
+            assert_eq!(check.actual, check.expected, "part {}", check.part);
+        }
+        Ok(())
+    }
+}
@@ -1,11 +1,26 @@
-use advent::io;
+use crate::solution::Solution;
 use anyhow::Result;
 
-fn main() -> Result<()> {
-    let input = io::for_day(15)?;
-    println!("{}", part1(&input));
-    println!("{}", part2(&input)?);
-    Ok(())
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: usize = 15;
+
+    type Input = String;
+    type Part1 = u64;
+    type Part2 = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        Ok(part1(input))
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        part2(input)
+    }
 }
 
 fn part1(input: &str) -> u64 {
@@ -1,47 +1,64 @@
-use std::{
-    collections::{HashMap, VecDeque},
-    str::FromStr,
-};
+use std::collections::{HashMap, VecDeque};
 
-use advent::io;
+use crate::{
+    geom::{Direction4 as Direction, Point2},
+    grid::Grid,
+    render::{Colour, Format, Renderer, Style},
+    solution::Solution,
+};
 use anyhow::{anyhow, bail, Error, Result};
 
-fn main() -> Result<()> {
-    let input = io::for_day(16)?;
-    println!("{}", part1(&input)?);
-    println!("{}", part2(&input)?);
-    Ok(())
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: usize = 16;
+
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        part2(input)
+    }
 }
 
 fn part1(input: &str) -> Result<usize> {
-    let grid = Grid::from_str(input)?;
+    let grid = input.parse::<Grid<Tile>>()?;
     count_energized(
         &grid,
         Beam {
-            position: (0, 0),
+            position: Point2::new(0, 0),
             direction: Direction::Right,
         },
     )
 }
 
 fn part2(input: &str) -> Result<usize> {
-    let grid = Grid::from_str(input)?;
+    let grid = input.parse::<Grid<Tile>>()?;
 
-    (0..grid.height as isize)
+    (0..grid.height() as isize)
         .map(|row| Beam {
-            position: (row, 0),
+            position: Point2::new(0, row),
             direction: Direction::Right,
         })
-        .chain((0..grid.width as isize).map(|column| Beam {
-            position: (0, column),
+        .chain((0..grid.width() as isize).map(|column| Beam {
+            position: Point2::new(column, 0),
             direction: Direction::Down,
         }))
-        .chain((0..grid.height as isize).map(|row| Beam {
-            position: (row, grid.width as isize - 1),
+        .chain((0..grid.height() as isize).map(|row| Beam {
+            position: Point2::new(grid.width() as isize - 1, row),
             direction: Direction::Left,
         }))
-        .chain((0..grid.width as isize).map(|column| Beam {
-            position: (grid.height as isize - 1, column),
+        .chain((0..grid.width() as isize).map(|column| Beam {
+            position: Point2::new(column, grid.height() as isize - 1),
             direction: Direction::Up,
         }))
         .flat_map(|beam| count_energized(&grid, beam))
@@ -49,14 +66,6 @@ fn part2(input: &str) -> Result<usize> {
         .ok_or(anyhow!("No data"))
 }
 
-#[derive(Debug, PartialEq, Eq, Copy, Clone)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
 #[derive(Debug, PartialEq, Eq)]
 enum Tile {
     Empty,
@@ -83,96 +92,34 @@ impl TryFrom<char> for Tile {
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 struct Beam {
-    position: (isize, isize),
+    position: Point2<isize>,
     direction: Direction,
 }
 
 impl Beam {
-    fn next(&self) -> (isize, isize) {
-        match self.direction {
-            Direction::Up => (self.position.0 - 1, self.position.1),
-            Direction::Down => (self.position.0 + 1, self.position.1),
-            Direction::Left => (self.position.0, self.position.1 - 1),
-            Direction::Right => (self.position.0, self.position.1 + 1),
-        }
-    }
-}
-
-#[derive(Debug, PartialEq, Eq)]
-struct Grid {
-    width: usize,
-    height: usize,
-    data: Vec<Tile>,
-}
-
-impl FromStr for Grid {
-    type Err = Error;
-
-    fn from_str(s: &str) -> Result<Self> {
-        let mut width = 0;
-        let mut height = 0;
-        let mut data = Vec::new();
-
-        for line in s.lines() {
-            width = line.len();
-            height += 1;
-            data.extend(
-                line.chars()
-                    .map(Tile::try_from)
-                    .collect::<Result<Vec<Tile>>>()?,
-            );
-        }
-
-        Ok(Grid {
-            width,
-            height,
-            data,
-        })
-    }
-}
-
-impl Grid {
-    fn get(&self, row: isize, column: isize) -> Option<&Tile> {
-        if row < 0 || column < 0 {
-            return None;
-        }
-
-        let row = row as usize;
-        let column = column as usize;
-
-        self.data.get(row * self.width + column)
+    fn next(&self) -> Point2<isize> {
+        self.position.step(self.direction)
     }
 }
 
 // keep track of each grid point that has been hit by a beam going a particular direction
 // if a beam hits a point that has already been hit, then we have a cycle
 // if a beam hits a point that is outside the bounds of the grid, then we have hit the edge of the map
-fn count_energized(grid: &Grid, starting_beam: Beam) -> Result<usize> {
-    let mut history: HashMap<(isize, isize), Vec<Direction>> = HashMap::new();
+fn count_energized(grid: &Grid<Tile>, starting_beam: Beam) -> Result<usize> {
+    Ok(energized(grid, starting_beam).len())
+}
+
+fn energized(grid: &Grid<Tile>, starting_beam: Beam) -> HashMap<Point2<isize>, Vec<Direction>> {
+    let mut history: HashMap<Point2<isize>, Vec<Direction>> = HashMap::new();
 
     let mut beams = VecDeque::from([starting_beam]);
 
     while let Some(mut beam) = beams.pop_front() {
-        // println!(
-        //     "Processing beam starting at {:?}, facing {:?}",
-        //     beam.position, beam.direction
-        // );
-
-        loop {
-            // make sure we haven't gone out of bounds
-            if beam.position.0 < 0
-                || beam.position.1 < 0
-                || beam.position.0 >= grid.width as isize
-                || beam.position.1 >= grid.height as isize
-            {
-                // println!("Beam went out of bounds at: {:?}", beam.position);
-                break;
-            }
-
+        // stop once the beam goes out of bounds
+        while let Some(tile) = grid.get_point_signed(beam.position) {
             // check if we've already been here
             if let Some(directions) = history.get(&beam.position) {
                 if directions.contains(&beam.direction) {
-                    // println!("Found cycle at: {:?}", beam.position);
                     break;
                 }
             }
@@ -183,12 +130,6 @@ fn count_energized(grid: &Grid, starting_beam: Beam) -> Result<usize> {
                 .or_default()
                 .push(beam.direction);
 
-            let tile = grid
-                .get(beam.position.0, beam.position.1)
-                .ok_or(anyhow!("Beam went out of bounds"))?;
-
-            // println!("This beam is on a {:?} tile", tile);
-
             match tile {
                 Tile::Empty => (),
                 Tile::LeftMirror => match beam.direction {
@@ -225,15 +166,39 @@ fn count_energized(grid: &Grid, starting_beam: Beam) -> Result<usize> {
             }
 
             beam.position = beam.next();
-
-            // println!(
-            //     "Beam moved to {:?} and is facing {:?}",
-            //     beam.position, beam.direction
-            // );
         }
     }
 
-    Ok(history.len())
+    history
+}
+
+/// Draws the tiles lit up by a beam entering at the top left, for debugging.
+pub fn render(input: &str, format: Format, scale: usize) -> Result<Vec<u8>> {
+    let grid = input.parse::<Grid<Tile>>()?;
+    let bytes = renderer(&grid).to_bytes(format, scale);
+    Ok(bytes)
+}
+
+fn renderer(grid: &Grid<Tile>) -> Renderer<'_, Tile> {
+    let start = Beam {
+        position: Point2::new(0, 0),
+        direction: Direction::Right,
+    };
+    let lit = energized(grid, start)
+        .into_keys()
+        .flat_map(Point2::to_unsigned);
+
+    Renderer::new(grid, |tile| {
+        let glyph = match tile {
+            Tile::Empty => '.',
+            Tile::LeftMirror => '\\',
+            Tile::RightMirror => '/',
+            Tile::VerticalSplitter => '|',
+            Tile::HorizontalSplitter => '-',
+        };
+        Style::new(glyph).with_foreground(Colour::GREY)
+    })
+    .with_highlight(lit, Colour::YELLOW)
 }
 
 #[cfg(test)]
@@ -257,6 +222,16 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_render() -> Result<()> {
+        let grid = EXAMPLE_INPUT.parse::<Grid<Tile>>()?;
+        let ansi = renderer(&grid).to_ansi();
+
+        assert_eq!(ansi.lines().count(), 10);
+        assert_eq!(ansi.matches("\x1b[48;2;181;137;0m").count(), 46);
+        Ok(())
+    }
+
     #[test]
     fn test_part2() -> Result<()> {
         assert_eq!(part2(EXAMPLE_INPUT)?, 51);
@@ -0,0 +1,60 @@
+use crate::solution::Solution;
+use anyhow::Result;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: usize = 19;
+
+    type Input = String;
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        part2(input)
+    }
+}
+
+fn part1(_input: &str) -> Result<String> {
+    unimplemented!("You have to solve the puzzle first!")
+}
+
+fn part2(_input: &str) -> Result<String> {
+    unimplemented!("You have to solve the puzzle first!")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{examples, solution::Part};
+
+    #[test]
+    #[ignore]
+    fn test_part1() -> Result<()> {
+        let checks = examples::verify(&Day19, &[Part::One])?;
+        assert!(!checks.is_empty(), "no examples saved for part 1");
+        for check in checks {
+            assert_eq!(check.actual, check.expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[ignore]
+    fn test_part2() -> Result<()> {
+        let checks = examples::verify(&Day19, &[Part::Two])?;
+        assert!(!checks.is_empty(), "no examples saved for part 2");
+        for check in checks {
+            assert_eq!(check.actual, check.expected);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,98 @@
+use anyhow::{bail, Result};
+
+use crate::{render::Format, solution::Runnable};
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+/// Every day of the calendar, in order.
+pub static DAYS: [&dyn Runnable; 25] = [
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+    &day04::Day04,
+    &day05::Day05,
+    &day06::Day06,
+    &day07::Day07,
+    &day08::Day08,
+    &day09::Day09,
+    &day10::Day10,
+    &day11::Day11,
+    &day12::Day12,
+    &day13::Day13,
+    &day14::Day14,
+    &day15::Day15,
+    &day16::Day16,
+    &day17::Day17,
+    &day18::Day18,
+    &day19::Day19,
+    &day20::Day20,
+    &day21::Day21,
+    &day22::Day22,
+    &day23::Day23,
+    &day24::Day24,
+    &day25::Day25,
+];
+
+pub fn get(day: usize) -> Option<&'static dyn Runnable> {
+    DAYS.iter().find(|d| d.day() == day).copied()
+}
+
+/// Draws `input` for the days that know how to, for debugging.
+pub fn render(day: usize, input: &str, format: Format, scale: usize) -> Result<Vec<u8>> {
+    match day {
+        10 => day10::render(input, format, scale),
+        16 => day16::render(input, format, scale),
+        _ => bail!("Day {} has nothing to render", day),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_is_in_order() {
+        for (i, day) in DAYS.iter().enumerate() {
+            assert_eq!(day.day(), i + 1);
+        }
+    }
+
+    #[test]
+    fn test_get() {
+        assert_eq!(get(7).map(|d| d.day()), Some(7));
+        assert!(get(0).is_none());
+        assert!(get(26).is_none());
+    }
+
+    #[test]
+    fn test_render() -> Result<()> {
+        let ansi = render(16, ".|\n..", Format::Ansi, 1)?;
+        assert_eq!(String::from_utf8(ansi)?.lines().count(), 2);
+        assert!(render(1, "", Format::Svg, 10).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,256 @@
+use std::{
+    collections::BTreeMap,
+    fs::{self, create_dir_all, read_dir, read_to_string},
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, Result};
+use regex::Regex;
+
+use crate::{
+    answers::Check,
+    io,
+    puzzle::PuzzleId,
+    solution::{Part, Runnable},
+};
+
+/// An example input from a puzzle description and the answers it is given for.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Example {
+    pub input: String,
+    pub answers: BTreeMap<Part, String>,
+}
+
+/// Pulls the worked examples out of a saved puzzle page.
+///
+/// Each part's description is its own `<article>`. The last emphasized
+/// `<code>` in it is the answer, and it belongs to the first example block
+/// in that article. Later blocks are usually worked steps, and a part two
+/// without blocks of its own reuses the example from part one. Blocks that
+/// no answer is given for are left out.
+pub fn extract(html: &str) -> Result<Vec<Example>> {
+    let articles = Regex::new(r#"(?s)<article[^>]*>(.*?)</article>"#)?;
+    let blocks = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>")?;
+    let answers = Regex::new(r"(?s)<code><em>(.*?)</em></code>|<em><code>(.*?)</code></em>")?;
+
+    let mut inputs = Vec::new();
+    let mut found = BTreeMap::<usize, BTreeMap<Part, String>>::new();
+
+    for (article, part) in articles.captures_iter(html).zip(Part::values()) {
+        let article = article
+            .get(1)
+            .ok_or(anyhow!("Empty article for part {}", part))?
+            .as_str();
+
+        let first = inputs.len();
+        inputs.extend(blocks.captures_iter(article).map(|c| unescape(&c[1])));
+
+        let Some(answer) = answers.captures_iter(article).last() else {
+            continue;
+        };
+        let value = answer.get(1).or(answer.get(2)).map_or("", |m| m.as_str());
+
+        let example = if first < inputs.len() {
+            first
+        } else if let Some(&previous) = found.keys().last() {
+            previous
+        } else {
+            continue;
+        };
+
+        found
+            .entry(example)
+            .or_default()
+            .insert(part, unescape(value));
+    }
+
+    Ok(found
+        .into_iter()
+        .map(|(i, answers)| Example {
+            input: inputs[i].clone(),
+            answers,
+        })
+        .collect())
+}
+
+// the blocks are html, so drop the highlighting and decode what was escaped
+fn unescape(s: &str) -> String {
+    let tags = Regex::new(r"<[^>]*>").expect("tag pattern is valid");
+    tags.replace_all(s, "")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Saves each example as `<dir>/<n>/input`, with a `part1` or `part2` file
+/// next to it for every answer we know.
+pub fn write(dir: &Path, examples: &[Example]) -> Result<Vec<PathBuf>> {
+    let mut paths = Vec::new();
+
+    for (i, example) in examples.iter().enumerate() {
+        let dir = dir.join((i + 1).to_string());
+        create_dir_all(&dir)?;
+
+        let path = dir.join("input");
+        fs::write(&path, &example.input)?;
+        paths.push(path);
+
+        for (part, answer) in &example.answers {
+            let path = dir.join(format!("part{}", part));
+            fs::write(&path, format!("{}\n", answer))?;
+            paths.push(path);
+        }
+    }
+
+    Ok(paths)
+}
+
+/// Reads back the examples saved by [`write`], in order.
+pub fn load(dir: &Path) -> Result<Vec<Example>> {
+    let entries = match read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut numbered = BTreeMap::new();
+    for entry in entries {
+        let path = entry?.path();
+        let n = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.parse::<usize>().ok())
+            .ok_or(anyhow!("Unexpected example {}", path.display()))?;
+        numbered.insert(n, path);
+    }
+
+    numbered
+        .into_values()
+        .map(|dir| {
+            let mut answers = BTreeMap::new();
+            for part in Part::values() {
+                match read_to_string(dir.join(format!("part{}", part))) {
+                    Ok(answer) => {
+                        answers.insert(part, answer.trim().to_string());
+                    }
+                    Err(e) if e.kind() == ErrorKind::NotFound => {}
+                    Err(e) => return Err(e.into()),
+                }
+            }
+
+            Ok(Example {
+                input: read_to_string(dir.join("input"))?,
+                answers,
+            })
+        })
+        .collect()
+}
+
+/// The saved examples for `day` of this year's calendar.
+pub fn for_day(day: usize) -> Result<Vec<Example>> {
+    load(&io::examples_dir(PuzzleId::day(day)?))
+}
+
+/// Runs `parts` of `day` against each saved example that has an answer for
+/// them, for use from its tests.
+pub fn verify(day: &dyn Runnable, parts: &[Part]) -> Result<Vec<Check>> {
+    let mut checks = Vec::new();
+
+    for mut example in for_day(day.day())? {
+        example.answers.retain(|part, _| parts.contains(part));
+        if example.answers.is_empty() {
+            continue;
+        }
+
+        let parts = example.answers.keys().copied().collect::<Vec<_>>();
+        let actuals = day.run(&example.input, &parts)?;
+
+        checks.extend(example.answers.into_iter().zip(actuals).map(
+            |((part, expected), actual)| Check {
+                day: day.day(),
+                part,
+                expected,
+                actual,
+            },
+        ));
+    }
+
+    Ok(checks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 9: Mirage Maintenance ---</h2><p>For example:</p>
+<pre><code>0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45
+</code></pre>
+<p>A single history looks like this:</p>
+<pre><code>0   3   6   9  12  15
+  3   3   3   3   3
+    0   0   0   0
+</code></pre>
+<p>If you find the next value for each history in this example and add them together, you get <code><em>114</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>1731106378</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Adding the new values on the left side of each history in this example gives <code><em>2</em></code>.</p>
+</article>
+</main>"#;
+
+    fn example(input: &str, answers: &[(Part, &str)]) -> Example {
+        Example {
+            input: input.to_string(),
+            answers: answers
+                .iter()
+                .map(|&(part, answer)| (part, answer.to_string()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_extract() -> Result<()> {
+        let expected = example(
+            "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45\n",
+            &[(Part::One, "114"), (Part::Two, "2")],
+        );
+        assert_eq!(extract(PAGE)?, vec![expected]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_extract_separate_examples() -> Result<()> {
+        let page = "<article><pre><code>a &lt; b\n</code></pre><p>It is <code><em>1</em></code>.</p></article>\
+            <article><pre><code><em>x</em> &amp;&amp; y\n</code></pre><p>It is <em><code>2</code></em>.</p></article>";
+
+        assert_eq!(
+            extract(page)?,
+            vec![
+                example("a < b\n", &[(Part::One, "1")]),
+                example("x && y\n", &[(Part::Two, "2")]),
+            ]
+        );
+        assert_eq!(extract("<article><p>No examples</p></article>")?, vec![]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_and_load() -> Result<()> {
+        let dir = std::env::temp_dir().join(format!("advent-examples-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let examples = (1..=10)
+            .map(|i| example(&format!("{}\n", i), &[(Part::Two, &i.to_string())]))
+            .collect::<Vec<_>>();
+
+        assert_eq!(write(&dir, &examples)?.len(), 20);
+        assert_eq!(load(&dir)?, examples);
+        assert_eq!(load(&dir.join("missing"))?, vec![]);
+        Ok(())
+    }
+}
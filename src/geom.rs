@@ -0,0 +1,272 @@
+use std::ops::{Add, Sub};
+
+/// A position on a plane, with `x` growing to the right and `y` growing
+/// down, the way puzzle inputs are read.
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Copy, Clone, Default)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    /// The number of steps between two points when moving only along the axes.
+    pub fn manhattan(&self, other: &Self) -> T
+    where
+        T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T>,
+    {
+        // ordering first keeps this safe for unsigned coordinates
+        let distance = |a: T, b: T| if a > b { a - b } else { b - a };
+        distance(self.x, other.x) + distance(self.y, other.y)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point2<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point2<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Point2<isize> {
+    pub fn step(self, direction: impl Step) -> Self {
+        self + direction.delta()
+    }
+
+    /// The same point, unless it has gone past the top or left edge.
+    pub fn to_unsigned(self) -> Option<Point2<usize>> {
+        Some(Point2::new(
+            self.x.try_into().ok()?,
+            self.y.try_into().ok()?,
+        ))
+    }
+}
+
+impl Point2<usize> {
+    /// Moves one step, unless that would go past the top or left edge.
+    pub fn checked_step(self, direction: impl Step) -> Option<Self> {
+        let delta = direction.delta();
+        Some(Self::new(
+            self.x.checked_add_signed(delta.x)?,
+            self.y.checked_add_signed(delta.y)?,
+        ))
+    }
+
+    /// Moves one step, unless that would leave `bounds`.
+    pub fn step_within(self, direction: impl Step, bounds: Bounds) -> Option<Self> {
+        self.checked_step(direction)
+            .filter(|&point| bounds.contains(point))
+    }
+}
+
+/// The rectangle from `(0, 0)` up to but not including `(width, height)`.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Bounds {
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Bounds {
+    pub fn contains(&self, point: Point2<usize>) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+}
+
+/// Anything that moves a point by one step.
+pub trait Step: Copy {
+    fn delta(self) -> Point2<isize>;
+}
+
+/// Which way to turn.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+/// The four directions along the axes, clockwise from up.
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Copy, Clone)]
+pub enum Direction4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction4 {
+    pub const ALL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn turn(self, turn: Turn) -> Self {
+        match turn {
+            Turn::Left => self.turn_left(),
+            Turn::Right => self.turn_right(),
+        }
+    }
+}
+
+impl Step for Direction4 {
+    fn delta(self) -> Point2<isize> {
+        match self {
+            Self::Up => Point2::new(0, -1),
+            Self::Right => Point2::new(1, 0),
+            Self::Down => Point2::new(0, 1),
+            Self::Left => Point2::new(-1, 0),
+        }
+    }
+}
+
+/// The four directions along the axes and the four diagonals between them,
+/// clockwise from up.
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Copy, Clone)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    pub const ALL: [Self; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    /// An eighth of a turn clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// An eighth of a turn counter clockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+}
+
+impl Step for Direction8 {
+    fn delta(self) -> Point2<isize> {
+        match self {
+            Self::Up => Point2::new(0, -1),
+            Self::UpRight => Point2::new(1, -1),
+            Self::Right => Point2::new(1, 0),
+            Self::DownRight => Point2::new(1, 1),
+            Self::Down => Point2::new(0, 1),
+            Self::DownLeft => Point2::new(-1, 1),
+            Self::Left => Point2::new(-1, 0),
+            Self::UpLeft => Point2::new(-1, -1),
+        }
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(direction: Direction4) -> Self {
+        Self::ALL[direction as usize * 2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manhattan() {
+        assert_eq!(Point2::new(1, 6).manhattan(&Point2::new(5, 11)), 9);
+        assert_eq!(Point2::new(5usize, 11).manhattan(&Point2::new(1, 6)), 9);
+        assert_eq!(Point2::new(-2, 3).manhattan(&Point2::new(2, -3)), 10);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!(Point2::new(1, 2) + Point2::new(3, 4), Point2::new(4, 6));
+        assert_eq!(Point2::new(1, 2) - Point2::new(3, 4), Point2::new(-2, -2));
+    }
+
+    #[test]
+    fn test_turns() {
+        use Direction4::*;
+
+        assert_eq!(Up.turn_right(), Right);
+        assert_eq!(Up.turn_left(), Left);
+        assert_eq!(Left.turn_right(), Up);
+        assert_eq!(Right.reverse(), Left);
+        assert_eq!(Down.turn(Turn::Left), Right);
+
+        for direction in Direction8::ALL {
+            assert_eq!(direction.turn_right().turn_left(), direction);
+            assert_eq!(direction.reverse().reverse(), direction);
+        }
+        assert_eq!(Direction8::UpLeft.turn_right(), Direction8::Up);
+        assert_eq!(Direction8::from(Left), Direction8::Left);
+    }
+
+    #[test]
+    fn test_steps() {
+        // every direction and its reverse cancel out
+        for direction in Direction8::ALL {
+            let delta = direction.delta() + direction.reverse().delta();
+            assert_eq!(delta, Point2::default());
+        }
+
+        assert_eq!(Point2::new(0, 0).step(Direction4::Up), Point2::new(0, -1));
+        assert_eq!(Point2::new(0, -1).to_unsigned(), None);
+        assert_eq!(
+            Point2::new(3, 1).to_unsigned(),
+            Some(Point2::new(3usize, 1))
+        );
+        assert_eq!(Point2::new(0usize, 0).checked_step(Direction4::Up), None);
+        assert_eq!(
+            Point2::new(0usize, 0).checked_step(Direction8::DownRight),
+            Some(Point2::new(1, 1))
+        );
+
+        let bounds = Bounds {
+            width: 2,
+            height: 3,
+        };
+        assert_eq!(
+            Point2::new(1usize, 1).step_within(Direction4::Down, bounds),
+            Some(Point2::new(1, 2))
+        );
+        assert_eq!(
+            Point2::new(1usize, 1).step_within(Direction4::Right, bounds),
+            None
+        );
+    }
+}
@@ -0,0 +1,495 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use anyhow::{anyhow, bail, Error, Result};
+
+use crate::{
+    geom::{Bounds, Point2},
+    parse::Text,
+};
+
+/// A rectangle of cells stored row by row.
+///
+/// Every position is `(row, column)`, with `(0, 0)` in the top left corner.
+/// Anything taking a [`Point2`] instead reads `y` as the row and `x` as the
+/// column.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    data: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Wraps `data`, which must hold exactly `width * height` cells.
+    pub fn new(width: usize, height: usize, data: Vec<T>) -> Result<Self> {
+        if data.len() != width * height {
+            bail!(
+                "A {}x{} grid needs {} cells, got {}",
+                width,
+                height,
+                width * height,
+                data.len()
+            );
+        }
+
+        Ok(Self {
+            width,
+            height,
+            data,
+        })
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            data: vec![value; width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn bounds(&self) -> Bounds {
+        Bounds {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    pub fn contains(&self, row: usize, column: usize) -> bool {
+        row < self.height && column < self.width
+    }
+
+    fn index_of(&self, row: usize, column: usize) -> Option<usize> {
+        self.contains(row, column)
+            .then_some(row * self.width + column)
+    }
+
+    pub fn get(&self, row: usize, column: usize) -> Option<&T> {
+        self.index_of(row, column).map(|i| &self.data[i])
+    }
+
+    pub fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut T> {
+        self.index_of(row, column).map(|i| &mut self.data[i])
+    }
+
+    /// Like [`get`](Self::get), for positions that may have walked off the
+    /// top or left edge.
+    pub fn get_signed(&self, row: isize, column: isize) -> Option<&T> {
+        self.get(usize::try_from(row).ok()?, usize::try_from(column).ok()?)
+    }
+
+    pub fn get_point(&self, point: Point2<usize>) -> Option<&T> {
+        let (row, column) = point.into();
+        self.get(row, column)
+    }
+
+    /// Like [`get_point`](Self::get_point), for points that may have walked
+    /// off the top or left edge.
+    pub fn get_point_signed(&self, point: Point2<isize>) -> Option<&T> {
+        self.get_point(point.to_unsigned()?)
+    }
+
+    /// Replaces a cell, returning what was there before.
+    pub fn set(&mut self, row: usize, column: usize, value: T) -> Result<T> {
+        let (width, height) = (self.width, self.height);
+        let cell = self.get_mut(row, column).ok_or(anyhow!(
+            "({}, {}) is outside the {}x{} grid",
+            row,
+            column,
+            width,
+            height
+        ))?;
+
+        Ok(std::mem::replace(cell, value))
+    }
+
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        (row < self.height).then(|| &self.data[row * self.width..(row + 1) * self.width])
+    }
+
+    pub fn column(&self, column: usize) -> Option<impl Iterator<Item = &T>> {
+        (column < self.width).then(|| self.data.iter().skip(column).step_by(self.width))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).flat_map(|row| self.row(row))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).flat_map(|column| self.column(column))
+    }
+
+    /// Every cell, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.data.iter()
+    }
+
+    /// Every position, row by row.
+    pub fn coordinates(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |column| (row, column)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn cells(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.coordinates().zip(self.data.iter())
+    }
+
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<(usize, usize)> {
+        self.cells().find(|(_, v)| predicate(v)).map(|(p, _)| p)
+    }
+
+    pub fn find(&self, value: &T) -> Option<(usize, usize)>
+    where
+        T: PartialEq,
+    {
+        self.position(|v| v == value)
+    }
+
+    /// The positions above, right of, below and left of a cell, in that
+    /// order, leaving out any that are off the grid.
+    pub fn neighbours(&self, row: usize, column: usize) -> impl Iterator<Item = (usize, usize)> {
+        self.offsets(row, column, &[(-1, 0), (0, 1), (1, 0), (0, -1)])
+    }
+
+    /// Like [`neighbours`](Self::neighbours) but including the diagonals,
+    /// clockwise from above.
+    pub fn neighbours8(&self, row: usize, column: usize) -> impl Iterator<Item = (usize, usize)> {
+        self.offsets(
+            row,
+            column,
+            &[
+                (-1, 0),
+                (-1, 1),
+                (0, 1),
+                (1, 1),
+                (1, 0),
+                (1, -1),
+                (0, -1),
+                (-1, -1),
+            ],
+        )
+    }
+
+    fn offsets(
+        &self,
+        row: usize,
+        column: usize,
+        offsets: &[(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> {
+        let (width, height) = (self.width, self.height);
+        offsets
+            .iter()
+            .flat_map(move |&(dr, dc)| {
+                let row = row.checked_add_signed(dr)?;
+                let column = column.checked_add_signed(dc)?;
+                (row < height && column < width).then_some((row, column))
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            data: self.data.iter().map(f).collect(),
+        }
+    }
+
+    /// Swaps rows and columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width: self.height,
+            height: self.width,
+            data: self.columns().flatten().cloned().collect(),
+        }
+    }
+
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let mut rotated = self.transpose();
+        rotated.flip_horizontal();
+        rotated
+    }
+
+    pub fn rotate_counter_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let mut rotated = self.transpose();
+        rotated.flip_vertical();
+        rotated
+    }
+
+    /// Mirrors the grid left to right.
+    pub fn flip_horizontal(&mut self) {
+        if self.width > 0 {
+            self.data
+                .chunks_mut(self.width)
+                .for_each(|row| row.reverse());
+        }
+    }
+
+    /// Mirrors the grid top to bottom.
+    pub fn flip_vertical(&mut self) {
+        for row in 0..self.height / 2 {
+            let other = self.height - 1 - row;
+            for column in 0..self.width {
+                self.data
+                    .swap(row * self.width + column, other * self.width + column);
+            }
+        }
+    }
+}
+
+/// Unchecked access, which panics outside the grid.
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, column): (usize, usize)) -> &T {
+        self.get(row, column).unwrap_or_else(|| {
+            panic!(
+                "({}, {}) is outside the {}x{} grid",
+                row, column, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(row, column).unwrap_or_else(|| {
+            panic!(
+                "({}, {}) is outside the {}x{} grid",
+                row, column, width, height
+            )
+        })
+    }
+}
+
+impl<T> Index<Point2<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point2<usize>) -> &T {
+        &self[<(usize, usize)>::from(point)]
+    }
+}
+
+impl<T> IndexMut<Point2<usize>> for Grid<T> {
+    fn index_mut(&mut self, point: Point2<usize>) -> &mut T {
+        &mut self[<(usize, usize)>::from(point)]
+    }
+}
+
+/// The `(row, column)` position of a point.
+impl From<Point2<usize>> for (usize, usize) {
+    fn from(point: Point2<usize>) -> Self {
+        (point.y, point.x)
+    }
+}
+
+/// One line per row, each character converted with `T::try_from`. See
+/// [`Text::grid`], which reports where in the input anything went wrong.
+impl<T> FromStr for Grid<T>
+where
+    T: TryFrom<char>,
+    T::Error: Display,
+{
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(Text::new(s).grid()?)
+    }
+}
+
+impl<T> Display for Grid<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "abc
+def";
+
+    fn grid() -> Grid<char> {
+        EXAMPLE.parse().unwrap()
+    }
+
+    #[test]
+    fn test_from_str() -> Result<()> {
+        assert_eq!(grid(), Grid::new(3, 2, "abcdef".chars().collect())?);
+        assert_eq!("".parse::<Grid<char>>()?, Grid::new(0, 0, vec![])?);
+        assert!("ab\nc".parse::<Grid<char>>().is_err());
+        assert!(Grid::new(2, 2, vec![1, 2, 3]).is_err());
+
+        #[derive(Debug, PartialEq)]
+        struct Digit(u32);
+
+        impl TryFrom<char> for Digit {
+            type Error = Error;
+
+            fn try_from(c: char) -> Result<Self> {
+                c.to_digit(10)
+                    .map(Digit)
+                    .ok_or(anyhow!("{} is not a digit", c))
+            }
+        }
+
+        assert_eq!("12\n34".parse::<Grid<Digit>>()?.get(1, 0), Some(&Digit(3)));
+        assert!("12\n3x"
+            .parse::<Grid<Digit>>()
+            .unwrap_err()
+            .to_string()
+            .starts_with("line 2, column 2: "));
+        assert!("12\n3".parse::<Grid<Digit>>().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_access() -> Result<()> {
+        let mut grid = grid();
+
+        assert_eq!(grid.get(1, 2), Some(&'f'));
+        assert_eq!(grid[(0, 1)], 'b');
+        // the end of one row is not the start of the next
+        assert_eq!(grid.get(0, 3), None);
+        assert_eq!(grid.get(2, 0), None);
+        assert_eq!(grid.get_signed(-1, 0), None);
+        assert_eq!(grid.get_signed(1, 1), Some(&'e'));
+
+        assert_eq!(grid.set(0, 0, 'z')?, 'a');
+        assert!(grid.set(0, 3, 'z').is_err());
+        grid[(1, 0)] = 'y';
+        assert_eq!(grid.to_string(), "zbc\nyef");
+        Ok(())
+    }
+
+    #[test]
+    fn test_points() {
+        let mut grid = grid();
+
+        // x picks the column and y the row
+        assert_eq!(grid.get_point(Point2::new(2, 1)), Some(&'f'));
+        assert_eq!(grid.get_point(Point2::new(1, 2)), None);
+        assert_eq!(grid[Point2::new(1, 0)], 'b');
+        assert_eq!(grid.get_point_signed(Point2::new(0, -1)), None);
+        assert_eq!(grid.get_point_signed(Point2::new(1, 1)), Some(&'e'));
+
+        grid[Point2::new(0, 1)] = 'y';
+        assert_eq!(grid[(1, 0)], 'y');
+        assert_eq!(<(usize, usize)>::from(Point2::new(2, 1)), (1, 2));
+    }
+
+    #[test]
+    #[should_panic(expected = "outside the 3x2 grid")]
+    fn test_index_out_of_bounds() {
+        let _ = grid()[(2, 0)];
+    }
+
+    #[test]
+    fn test_views() {
+        let grid = grid();
+
+        assert_eq!(grid.row(1), Some(&['d', 'e', 'f'][..]));
+        assert_eq!(grid.row(2), None);
+        assert_eq!(
+            grid.column(2).map(|c| c.collect::<String>()),
+            Some("cf".to_string())
+        );
+        assert!(grid.column(3).is_none());
+
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(
+            grid.columns()
+                .map(|c| c.collect::<String>())
+                .collect::<Vec<_>>(),
+            vec!["ad", "be", "cf"]
+        );
+    }
+
+    #[test]
+    fn test_iterators() {
+        let grid = grid();
+
+        assert_eq!(grid.iter().collect::<String>(), "abcdef");
+        assert_eq!(
+            grid.coordinates().collect::<Vec<_>>(),
+            vec![(0, 0), (0, 1), (0, 2), (1, 0), (1, 1), (1, 2)]
+        );
+        assert_eq!(grid.cells().nth(4), Some(((1, 1), &'e')));
+        assert_eq!(grid.find(&'e'), Some((1, 1)));
+        assert_eq!(grid.position(|c| *c > 'c'), Some((1, 0)));
+        assert_eq!(grid.find(&'x'), None);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = grid();
+
+        assert_eq!(
+            grid.neighbours(0, 0).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(
+            grid.neighbours(1, 1).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 2), (1, 0)]
+        );
+        assert_eq!(
+            grid.neighbours8(1, 1).collect::<Vec<_>>(),
+            vec![(0, 1), (0, 2), (1, 2), (1, 0), (0, 0)]
+        );
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = grid();
+
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_counter_clockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(
+            grid.rotate_clockwise()
+                .rotate_clockwise()
+                .rotate_clockwise()
+                .rotate_clockwise(),
+            grid
+        );
+        assert_eq!(grid.map(|c| c.to_ascii_uppercase()).to_string(), "ABC\nDEF");
+    }
+}
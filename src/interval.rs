@@ -0,0 +1,487 @@
+//! Sets of half open ranges, and maps that shift whole ranges of numbers at
+//! once, for when the inputs are too big to walk one value at a time.
+
+use std::{
+    iter::Sum,
+    ops::{Range, Sub},
+};
+
+use anyhow::{bail, Result};
+
+/// A set of values stored as sorted, disjoint, non-adjacent ranges.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { ranges: Vec::new() }
+    }
+}
+
+impl<T: Ord + Copy> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|r| r.start)
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        let i = self.ranges.partition_point(|r| r.end <= *value);
+        self.ranges.get(i).is_some_and(|r| r.contains(value))
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        self.ranges.push(range);
+        self.normalise();
+    }
+
+    // drops empty ranges and merges the ones that overlap or touch
+    fn normalise(&mut self) {
+        self.ranges.retain(|r| r.start < r.end);
+        self.ranges.sort_by_key(|r| r.start);
+
+        let mut merged: Vec<Range<T>> = Vec::with_capacity(self.ranges.len());
+        for range in self.ranges.drain(..) {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+        }
+        self.ranges = merged;
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.ranges.iter().chain(&other.ranges).cloned().collect()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let start = a.start.max(b.start);
+            let end = a.end.min(b.end);
+            if start < end {
+                ranges.push(start..end);
+            }
+
+            // whichever finishes first can't overlap anything further on
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        Self { ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+
+        for range in &self.ranges {
+            let mut start = range.start;
+            for cut in other
+                .ranges
+                .iter()
+                .skip_while(|cut| cut.end <= range.start)
+                .take_while(|cut| cut.start < range.end)
+            {
+                if start < cut.start {
+                    ranges.push(start..cut.start);
+                }
+                start = start.max(cut.end);
+            }
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+
+        Self { ranges }
+    }
+
+    /// How many values are in the set.
+    pub fn size(&self) -> T
+    where
+        T: Sub<Output = T> + Sum,
+    {
+        self.ranges.iter().map(|r| r.end - r.start).sum()
+    }
+}
+
+impl<T: Ord + Copy> From<Range<T>> for IntervalSet<T> {
+    fn from(range: Range<T>) -> Self {
+        Self::from_iter([range])
+    }
+}
+
+impl<T: Ord + Copy> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut set = Self {
+            ranges: iter.into_iter().collect(),
+        };
+        set.normalise();
+        set
+    }
+}
+
+/// A function on integers that adds a constant offset within each piece of
+/// its domain, and leaves everything else where it is.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct PiecewiseMap {
+    // sorted, disjoint and never a zero offset, so equal maps compare equal
+    pieces: Vec<(Range<i64>, i64)>,
+}
+
+impl PiecewiseMap {
+    pub fn identity() -> Self {
+        Self::default()
+    }
+
+    /// Fails if any two pieces overlap, since then a value would have two images.
+    pub fn new(pieces: impl IntoIterator<Item = (Range<i64>, i64)>) -> Result<Self> {
+        let mut pieces = pieces
+            .into_iter()
+            .filter(|(domain, _)| !domain.is_empty())
+            .collect::<Vec<_>>();
+        pieces.sort_by_key(|(domain, _)| domain.start);
+
+        for pair in pieces.windows(2) {
+            let [(a, _), (b, _)] = pair else {
+                unreachable!()
+            };
+            if b.start < a.end {
+                bail!("{:?} overlaps {:?}", a, b);
+            }
+        }
+
+        Ok(Self::from_sorted(pieces))
+    }
+
+    fn from_sorted(pieces: Vec<(Range<i64>, i64)>) -> Self {
+        let mut merged: Vec<(Range<i64>, i64)> = Vec::with_capacity(pieces.len());
+        for (domain, offset) in pieces {
+            if domain.is_empty() || offset == 0 {
+                continue;
+            }
+            match merged.last_mut() {
+                Some((last, last_offset)) if last.end == domain.start && *last_offset == offset => {
+                    last.end = domain.end
+                }
+                _ => merged.push((domain, offset)),
+            }
+        }
+        Self { pieces: merged }
+    }
+
+    pub fn pieces(&self) -> &[(Range<i64>, i64)] {
+        &self.pieces
+    }
+
+    fn offset(&self, value: i64) -> i64 {
+        let i = self
+            .pieces
+            .partition_point(|(domain, _)| domain.end <= value);
+        match self.pieces.get(i) {
+            Some((domain, offset)) if domain.contains(&value) => *offset,
+            _ => 0,
+        }
+    }
+
+    pub fn apply(&self, value: i64) -> i64 {
+        value + self.offset(value)
+    }
+
+    // cuts `range` wherever the offset changes
+    fn segments(&self, range: Range<i64>) -> Vec<(Range<i64>, i64)> {
+        let mut segments = Vec::new();
+        let mut start = range.start;
+
+        for (domain, offset) in &self.pieces {
+            if domain.end <= start {
+                continue;
+            }
+            if domain.start >= range.end {
+                break;
+            }
+            if start < domain.start {
+                segments.push((start..domain.start, 0));
+            }
+            let end = domain.end.min(range.end);
+            segments.push((start.max(domain.start)..end, *offset));
+            start = end;
+        }
+        if start < range.end {
+            segments.push((start..range.end, 0));
+        }
+
+        segments
+    }
+
+    /// Everything `range` is sent to.
+    pub fn apply_range(&self, range: Range<i64>) -> IntervalSet<i64> {
+        self.segments(range)
+            .into_iter()
+            .map(|(r, offset)| r.start + offset..r.end + offset)
+            .collect()
+    }
+
+    pub fn apply_set(&self, set: &IntervalSet<i64>) -> IntervalSet<i64> {
+        set.ranges()
+            .iter()
+            .flat_map(|range| self.apply_range(range.clone()).ranges)
+            .collect()
+    }
+
+    /// Everything that is sent into `range`.
+    pub fn preimage(&self, range: Range<i64>) -> IntervalSet<i64> {
+        let domains = self.pieces.iter().map(|(domain, _)| domain.clone());
+        let untouched = IntervalSet::from(range.clone()).difference(&domains.collect());
+
+        self.pieces
+            .iter()
+            .map(|(domain, offset)| {
+                (range.start - offset).max(domain.start)..(range.end - offset).min(domain.end)
+            })
+            .chain(untouched.ranges)
+            .collect()
+    }
+
+    /// This map followed by `next`.
+    pub fn then(&self, next: &Self) -> Self {
+        // the combined offset can only change where this map's does, or just
+        // before a value lands on a boundary of `next`
+        let mut cuts = Vec::new();
+        for (domain, _) in &self.pieces {
+            cuts.extend([domain.start, domain.end]);
+        }
+        for (domain, _) in &next.pieces {
+            for boundary in [domain.start, domain.end] {
+                let landing = self.preimage(boundary..boundary + 1);
+                cuts.extend(landing.ranges.iter().map(|r| r.start));
+            }
+        }
+        cuts.sort();
+        cuts.dedup();
+
+        let pieces = cuts
+            .windows(2)
+            .map(|pair| {
+                let first = self.offset(pair[0]);
+                (pair[0]..pair[1], first + next.offset(pair[0] + first))
+            })
+            .collect();
+
+        Self::from_sorted(pieces)
+    }
+
+    /// The map that undoes this one, if no two values end up in the same place.
+    pub fn invert(&self) -> Option<Self> {
+        let domains = self.pieces.iter().map(|(domain, _)| domain.clone());
+        let images = self
+            .pieces
+            .iter()
+            .map(|(domain, offset)| domain.start + offset..domain.end + offset);
+
+        let domains: IntervalSet<i64> = domains.collect();
+        let moved: i64 = images.clone().map(|r| r.end - r.start).sum();
+        let images: IntervalSet<i64> = images.collect();
+
+        // the pieces must land without overlapping each other, and exactly
+        // fill the space they left, or they'd collide with unmoved values
+        if images.size() != moved || images != domains {
+            return None;
+        }
+
+        Self::new(
+            self.pieces
+                .iter()
+                .map(|(domain, offset)| (domain.start + offset..domain.end + offset, -offset)),
+        )
+        .ok()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::single_range_in_vec_init)]
+mod tests {
+    use super::*;
+    use quickcheck::Arbitrary;
+
+    // keeps everything small enough to check value by value
+    const VALUES: Range<i64> = -80..80;
+
+    #[derive(Debug, Clone)]
+    struct Set(IntervalSet<i64>);
+
+    impl Arbitrary for Set {
+        fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+            let ranges = Vec::<(i8, u8)>::arbitrary(g)
+                .into_iter()
+                .map(|(start, length)| {
+                    let start = start as i64 % 40;
+                    start..start + length as i64 % 10
+                });
+            Set(ranges.collect())
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    struct Map(PiecewiseMap);
+
+    impl Arbitrary for Map {
+        fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+            let mut start = -30;
+            let pieces = Vec::<(u8, u8, i8)>::arbitrary(g)
+                .into_iter()
+                .take(8)
+                .map(|(gap, length, offset)| {
+                    start += gap as i64 % 5;
+                    let domain = start..start + length as i64 % 6 + 1;
+                    start = domain.end;
+                    (domain, offset as i64 % 10)
+                })
+                .collect::<Vec<_>>();
+            Map(PiecewiseMap::new(pieces).unwrap())
+        }
+    }
+
+    fn values(set: &IntervalSet<i64>) -> Vec<i64> {
+        VALUES.filter(|v| set.contains(v)).collect()
+    }
+
+    #[test]
+    fn test_normalise() {
+        let set: IntervalSet<i64> = [5..8, 0..2, 2..3, 7..10, 4..4].into_iter().collect();
+        assert_eq!(set.ranges(), &[0..3, 5..10]);
+        assert_eq!(set.size(), 8);
+        assert!(set.contains(&9));
+        assert!(!set.contains(&3));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a: IntervalSet<i64> = [0..10, 20..30].into_iter().collect();
+        let b = IntervalSet::from(5..25);
+
+        assert_eq!(a.union(&b).ranges(), &[0..30]);
+        assert_eq!(a.intersection(&b).ranges(), &[5..10, 20..25]);
+        assert_eq!(a.difference(&b).ranges(), &[0..5, 25..30]);
+        assert_eq!(b.difference(&a).ranges(), &[10..20]);
+    }
+
+    #[test]
+    fn test_overlapping_pieces() {
+        assert!(PiecewiseMap::new([(0..10, 1), (9..12, 2)]).is_err());
+        assert!(PiecewiseMap::new([(0..10, 1), (10..12, 2)]).is_ok());
+    }
+
+    #[test]
+    fn test_apply_range() -> Result<()> {
+        // the seed-to-soil map from 2023 day 5
+        let map = PiecewiseMap::new([(98..100, -48), (50..98, 2)])?;
+
+        assert_eq!(map.apply(79), 81);
+        assert_eq!(map.apply(10), 10);
+        assert_eq!(map.apply_range(79..93).ranges(), &[81..95]);
+        assert_eq!(map.apply_range(45..100).ranges(), &[45..100]);
+        assert_eq!(map.apply_range(97..101).ranges(), &[50..52, 99..101]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_invert() -> Result<()> {
+        let map = PiecewiseMap::new([(98..100, -48), (50..98, 2)])?;
+        assert_eq!(
+            map.invert(),
+            Some(PiecewiseMap::new([(50..52, 48), (52..100, -2)])?)
+        );
+
+        // 0 and 5 both end up at 5
+        assert_eq!(PiecewiseMap::new([(0..1, 5)])?.invert(), None);
+        Ok(())
+    }
+
+    #[quickcheck_macros::quickcheck]
+    fn test_set_operations_match_values(a: Set, b: Set) -> bool {
+        let (a, b) = (a.0, b.0);
+        let (va, vb) = (values(&a), values(&b));
+
+        values(&a.union(&b))
+            == VALUES
+                .filter(|v| va.contains(v) || vb.contains(v))
+                .collect::<Vec<_>>()
+            && values(&a.intersection(&b))
+                == va
+                    .iter()
+                    .copied()
+                    .filter(|v| vb.contains(v))
+                    .collect::<Vec<_>>()
+            && values(&a.difference(&b))
+                == va
+                    .iter()
+                    .copied()
+                    .filter(|v| !vb.contains(v))
+                    .collect::<Vec<_>>()
+    }
+
+    #[quickcheck_macros::quickcheck]
+    fn test_apply_range_matches_values(map: Map, start: i8, length: u8) -> bool {
+        let start = start as i64 % 40;
+        let range = start..start + length as i64 % 30;
+
+        let expected: IntervalSet<i64> = range
+            .clone()
+            .map(|v| map.0.apply(v)..map.0.apply(v) + 1)
+            .collect();
+        map.0.apply_range(range) == expected
+    }
+
+    #[quickcheck_macros::quickcheck]
+    fn test_preimage_matches_values(map: Map, start: i8, length: u8) -> bool {
+        let start = start as i64 % 40;
+        let range = start..start + length as i64 % 30;
+
+        let preimage = map.0.preimage(range.clone());
+        VALUES
+            .into_iter()
+            .all(|v| preimage.contains(&v) == range.contains(&map.0.apply(v)))
+    }
+
+    #[quickcheck_macros::quickcheck]
+    fn test_then_matches_values(first: Map, second: Map) -> bool {
+        let composed = first.0.then(&second.0);
+        VALUES
+            .into_iter()
+            .all(|v| composed.apply(v) == second.0.apply(first.0.apply(v)))
+    }
+
+    #[quickcheck_macros::quickcheck]
+    fn test_invert_round_trips(map: Map) -> bool {
+        let Some(inverse) = map.0.invert() else {
+            // not invertible, so two values must share an image
+            let mut images = VALUES.map(|v| map.0.apply(v)).collect::<Vec<_>>();
+            images.sort();
+            let before = images.len();
+            images.dedup();
+            return images.len() < before;
+        };
+
+        VALUES
+            .into_iter()
+            .all(|v| inverse.apply(map.0.apply(v)) == v)
+            && map.0.then(&inverse) == PiecewiseMap::identity()
+    }
+}
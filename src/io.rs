@@ -1,32 +1,276 @@
-use std::fs::read_to_string;
-use std::io::Error;
-use std::usize;
-
-pub fn for_day(day: usize) -> Result<String, Error> {
-    if day == 0 || day > 25 {
-        return Err(Error::new(
-            std::io::ErrorKind::InvalidInput,
-            format!("Invalid day: {}", day),
-        ));
+use std::env;
+use std::fmt::Display;
+use std::fs::{create_dir_all, read, write};
+use std::io::{stdin, Error, ErrorKind, Read};
+use std::path::{Path, PathBuf};
+
+use crate::{
+    client::Client,
+    puzzle::{days_in, PuzzleId, FIRST_YEAR},
+};
+
+/// Everything that can go wrong while loading a puzzle input.
+#[derive(Debug)]
+pub enum InputError {
+    /// The year or day is not on any Advent of Code calendar.
+    InvalidDay {
+        year: usize,
+        day: usize,
+    },
+    /// The input is not on disk and could not be downloaded.
+    Missing {
+        path: PathBuf,
+        puzzle: Option<PuzzleId>,
+        searched: Vec<PathBuf>,
+        reason: Option<String>,
+    },
+    /// The input exists but could not be read (or cached).
+    Unreadable {
+        path: PathBuf,
+        source: Error,
+    },
+    NotUtf8 {
+        path: PathBuf,
+    },
+    Empty {
+        path: PathBuf,
+    },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidDay { year, day } if *year < FIRST_YEAR => write!(
+                f,
+                "Invalid year {}, the first Advent of Code was in {}",
+                year, FIRST_YEAR
+            ),
+            Self::InvalidDay { year, day } => write!(
+                f,
+                "Invalid day {}, {} has puzzles on days 1 to {}",
+                day,
+                year,
+                days_in(*year)
+            ),
+            Self::Missing {
+                path,
+                puzzle,
+                searched,
+                reason,
+            } => {
+                let Some(puzzle) = puzzle else {
+                    return write!(f, "{} does not exist", path.display());
+                };
+
+                writeln!(f, "No input for {}, looked in", puzzle)?;
+                for path in searched {
+                    writeln!(f, "    {}", path.display())?;
+                }
+                if let Some(reason) = reason {
+                    writeln!(f, "and downloading it failed: {}", reason)?;
+                }
+                write!(
+                    f,
+                    "Run `advent fetch {} --year {}` with ADVENT_SESSION set, or save it to {}",
+                    puzzle.day,
+                    puzzle.year,
+                    path.display()
+                )
+            }
+            Self::Unreadable { path, source } => {
+                write!(f, "Could not access {}: {}", path.display(), source)
+            }
+            Self::NotUtf8 { path } => write!(
+                f,
+                "{} is not valid UTF-8, was it saved from the browser with a different encoding?",
+                path.display()
+            ),
+            Self::Empty { path } => write!(
+                f,
+                "{} is empty, delete it and run `advent fetch` to download it again",
+                path.display()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Unreadable { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+// every input directory is laid out as <dir>/<year>/day/<day>/input
+fn relative_path(puzzle: PuzzleId) -> PathBuf {
+    PathBuf::from(format!("{}/day/{}/input", puzzle.year, puzzle.day))
+}
+
+fn crate_input_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("input")
+}
+
+/// The directories searched for inputs, in order: `ADVENT_INPUT_DIR`,
+/// `input` under the working directory, then `input` in the crate root.
+pub fn input_dirs() -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+
+    if let Some(dir) = env::var_os("ADVENT_INPUT_DIR") {
+        dirs.push(PathBuf::from(dir));
+    }
+
+    let root = crate_input_dir();
+    if let Ok(cwd) = env::current_dir() {
+        let local = cwd.join("input");
+        if local != root {
+            dirs.push(local);
+        }
+    }
+    dirs.push(root);
+
+    dirs
+}
+
+/// Where the input for `puzzle` is saved when it has to be downloaded.
+pub fn input_path(puzzle: PuzzleId) -> PathBuf {
+    env::var_os("ADVENT_INPUT_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(crate_input_dir)
+        .join(relative_path(puzzle))
+}
+
+/// Where the worked examples for `puzzle` are kept, next to its input in the crate.
+pub fn examples_dir(puzzle: PuzzleId) -> PathBuf {
+    crate_input_dir()
+        .join(relative_path(puzzle))
+        .with_file_name("examples")
+}
+
+/// The first place the input for `puzzle` can be found, if any.
+pub fn locate(puzzle: PuzzleId) -> Option<PathBuf> {
+    input_dirs()
+        .into_iter()
+        .map(|dir| dir.join(relative_path(puzzle)))
+        .find(|path| path.is_file())
+}
+
+/// Reads the input for `day` of this year's calendar.
+pub fn for_day(day: usize) -> Result<String, InputError> {
+    for_puzzle(PuzzleId::day(day)?)
+}
+
+/// Reads the input for any year and day, validating the date first.
+pub fn for_year(year: usize, day: usize) -> Result<String, InputError> {
+    for_puzzle(PuzzleId::new(year, day)?)
+}
+
+/// Reads the input for `puzzle` from the first input directory that has it,
+/// downloading it first if none of them do.
+pub fn for_puzzle(puzzle: PuzzleId) -> Result<String, InputError> {
+    let candidates = input_dirs()
+        .into_iter()
+        .map(|dir| dir.join(relative_path(puzzle)))
+        .collect::<Vec<_>>();
+
+    search(puzzle, &candidates, &input_path(puzzle), || {
+        Client::from_env()?.input(puzzle)
+    })
+}
+
+/// Reads an input from an explicit path, where `-` means stdin.
+pub fn from_path(path: &Path) -> Result<String, InputError> {
+    if path == Path::new("-") {
+        let mut bytes = Vec::new();
+        stdin()
+            .read_to_end(&mut bytes)
+            .map_err(|source| InputError::Unreadable {
+                path: path.to_path_buf(),
+                source,
+            })?;
+        return decode(path, bytes);
+    }
+
+    match read(path) {
+        Ok(bytes) => decode(path, bytes),
+        Err(e) if e.kind() == ErrorKind::NotFound => Err(InputError::Missing {
+            path: path.to_path_buf(),
+            puzzle: None,
+            searched: vec![path.to_path_buf()],
+            reason: None,
+        }),
+        Err(source) => Err(InputError::Unreadable {
+            path: path.to_path_buf(),
+            source,
+        }),
+    }
+}
+
+fn decode(path: &Path, bytes: Vec<u8>) -> Result<String, InputError> {
+    let input = String::from_utf8(bytes).map_err(|_| InputError::NotUtf8 {
+        path: path.to_path_buf(),
+    })?;
+
+    if input.trim().is_empty() {
+        return Err(InputError::Empty {
+            path: path.to_path_buf(),
+        });
+    }
+
+    Ok(input)
+}
+
+fn search(
+    puzzle: PuzzleId,
+    candidates: &[PathBuf],
+    cache: &Path,
+    fetch: impl FnOnce() -> anyhow::Result<String>,
+) -> Result<String, InputError> {
+    for path in candidates {
+        match from_path(path) {
+            Err(InputError::Missing { .. }) => continue,
+            result => return result,
+        }
     }
 
-    let path = format!("input/day/{}/input", day);
+    let input = fetch().map_err(|e| InputError::Missing {
+        path: cache.to_path_buf(),
+        puzzle: Some(puzzle),
+        searched: candidates.to_vec(),
+        reason: Some(format!("{:#}", e)),
+    })?;
 
-    read_to_string(path)
+    let unwritable = |source| InputError::Unreadable {
+        path: cache.to_path_buf(),
+        source,
+    };
+    if let Some(dir) = cache.parent() {
+        create_dir_all(dir).map_err(unwritable)?;
+    }
+    write(cache, &input).map_err(unwritable)?;
+
+    decode(cache, input.into_bytes())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{
+        mock_server::MockServer,
+        puzzle::{days_in, FIRST_YEAR, YEAR},
+    };
     use quickcheck::Arbitrary;
+    use std::time::Duration;
 
     #[derive(Debug, Clone, Copy)]
-    struct ChristmasDay(usize);
+    struct ChristmasDay(PuzzleId);
 
     impl Arbitrary for ChristmasDay {
         fn arbitrary(g: &mut quickcheck::Gen) -> Self {
-            let day = usize::arbitrary(g) % 25 + 1;
-            ChristmasDay(day)
+            let year = FIRST_YEAR + usize::arbitrary(g) % 20;
+            let day = usize::arbitrary(g) % days_in(year) + 1;
+            ChristmasDay(PuzzleId { year, day })
         }
 
         fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
@@ -35,11 +279,20 @@ mod tests {
     }
 
     #[derive(Debug, Clone, Copy)]
-    struct NonChristmasDay(usize);
+    struct NonChristmasDay(usize, usize);
     impl Arbitrary for NonChristmasDay {
         fn arbitrary(g: &mut quickcheck::Gen) -> Self {
-            let day = usize::arbitrary(g) % usize::MAX + 26;
-            NonChristmasDay(day)
+            let year = FIRST_YEAR + usize::arbitrary(g) % 20;
+            let day = usize::arbitrary(g) % 1000;
+
+            match g.choose(&[0, 1, 2]).unwrap() {
+                // before the first event
+                0 => NonChristmasDay(usize::arbitrary(g) % FIRST_YEAR, day),
+                // there is no day zero
+                1 => NonChristmasDay(year, 0),
+                // after christmas
+                _ => NonChristmasDay(year, days_in(year) + 1 + day),
+            }
         }
 
         fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
@@ -48,12 +301,156 @@ mod tests {
     }
 
     #[quickcheck_macros::quickcheck]
-    fn test_for_day_invalid(input: NonChristmasDay) -> bool {
-        for_day(input.0).is_err()
+    fn test_puzzle_id_valid(input: ChristmasDay) -> bool {
+        PuzzleId::new(input.0.year, input.0.day).is_ok()
+    }
+
+    #[quickcheck_macros::quickcheck]
+    fn test_puzzle_id_invalid(input: NonChristmasDay) -> bool {
+        PuzzleId::new(input.0, input.1).is_err()
+    }
+
+    #[quickcheck_macros::quickcheck]
+    fn test_input_path(input: ChristmasDay) -> bool {
+        input_path(input.0).ends_with(format!("{}/day/{}/input", input.0.year, input.0.day))
+    }
+
+    #[quickcheck_macros::quickcheck]
+    fn test_for_year_invalid(input: NonChristmasDay) -> bool {
+        for_year(input.0, input.1).is_err()
     }
 
     #[quickcheck_macros::quickcheck]
     fn test_for_day_valid(input: ChristmasDay) -> bool {
-        for_day(input.0).is_ok()
+        // only this year's inputs are checked in, and the unsolved days are empty placeholders
+        let readable = |result| matches!(result, Ok(_) | Err(InputError::Empty { .. }));
+        readable(for_year(YEAR, input.0.day)) && readable(for_day(input.0.day))
+    }
+
+    #[test]
+    fn test_search() -> anyhow::Result<()> {
+        let server = MockServer::start(vec![(200, "0 3 6 9 12 15\n")]);
+        let client = Client::new(&server.base_url, "abc123").with_min_interval(Duration::ZERO);
+
+        let dir = std::env::temp_dir().join(format!("advent-io-search-{}", std::process::id()));
+        let puzzle = PuzzleId::day(9)?;
+        let relative = relative_path(puzzle);
+        let candidates = vec![
+            dir.join("first").join(&relative),
+            dir.join("second").join(&relative),
+        ];
+
+        assert_eq!(
+            search(puzzle, &candidates, &candidates[1], || client
+                .input(PuzzleId::day(9)?))?,
+            "0 3 6 9 12 15\n"
+        );
+        assert_eq!(std::fs::read_to_string(&candidates[1])?, "0 3 6 9 12 15\n");
+
+        // the second read comes straight from disk
+        assert_eq!(
+            search(puzzle, &candidates, &candidates[1], || panic!(
+                "should not fetch twice"
+            ))?,
+            "0 3 6 9 12 15\n"
+        );
+        assert_eq!(server.requests().len(), 1);
+
+        // earlier directories win
+        create_dir_all(candidates[0].parent().unwrap())?;
+        write(&candidates[0], "1 2 3\n")?;
+        assert_eq!(
+            search(puzzle, &candidates, &candidates[1], || panic!(
+                "should not fetch"
+            ))?,
+            "1 2 3\n"
+        );
+
+        std::fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_search_lists_candidates() -> anyhow::Result<()> {
+        let candidates = vec![PathBuf::from("/nowhere/a"), PathBuf::from("/nowhere/b")];
+        let error = search(
+            PuzzleId::day(3)?,
+            &candidates,
+            Path::new("/nowhere/a"),
+            || anyhow::bail!("no session"),
+        )
+        .unwrap_err();
+
+        assert!(matches!(error, InputError::Missing { .. }));
+        let message = error.to_string();
+        assert!(message.contains("/nowhere/a"));
+        assert!(message.contains("/nowhere/b"));
+        assert!(message.contains("no session"));
+        assert!(message.contains("advent fetch 3 --year 2023"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_path() -> anyhow::Result<()> {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("input/2023/day/1/input");
+        assert_eq!(from_path(&path)?, for_day(1)?);
+
+        let error = from_path(Path::new("/nowhere/input")).unwrap_err();
+        assert!(matches!(error, InputError::Missing { puzzle: None, .. }));
+        assert_eq!(error.to_string(), "/nowhere/input does not exist");
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_path_errors() -> anyhow::Result<()> {
+        let dir = std::env::temp_dir().join(format!("advent-io-errors-{}", std::process::id()));
+        create_dir_all(&dir)?;
+
+        let latin1 = dir.join("latin1");
+        write(&latin1, [b'c', b'a', b'f', 0xe9])?;
+        assert!(matches!(
+            from_path(&latin1),
+            Err(InputError::NotUtf8 { .. })
+        ));
+
+        let empty = dir.join("empty");
+        write(&empty, "\n")?;
+        assert!(matches!(from_path(&empty), Err(InputError::Empty { .. })));
+
+        // a directory exists but can't be read as a file
+        assert!(matches!(
+            from_path(&dir),
+            Err(InputError::Unreadable { .. })
+        ));
+
+        std::fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_day() {
+        let error = for_year(2023, 26).unwrap_err();
+        assert!(matches!(
+            error,
+            InputError::InvalidDay {
+                year: 2023,
+                day: 26
+            }
+        ));
+        assert_eq!(
+            error.to_string(),
+            "Invalid day 26, 2023 has puzzles on days 1 to 25"
+        );
+        assert_eq!(
+            for_year(2014, 1).unwrap_err().to_string(),
+            "Invalid year 2014, the first Advent of Code was in 2015"
+        );
+    }
+
+    #[test]
+    fn test_locate() -> anyhow::Result<()> {
+        assert!(locate(PuzzleId::day(1)?).is_some());
+        assert!(locate(PuzzleId::new(2015, 1)?).is_none());
+        Ok(())
     }
 }
@@ -1 +1,22 @@
+pub mod answers;
+pub mod bench;
+pub mod calendar;
+pub mod client;
+pub mod cycle;
+pub mod days;
+pub mod examples;
+pub mod geom;
+pub mod grid;
+pub mod interval;
 pub mod io;
+pub mod math;
+pub mod parse;
+pub mod puzzle;
+pub mod render;
+pub mod scaffold;
+pub mod search;
+pub mod solution;
+pub mod submit;
+
+#[cfg(test)]
+mod mock_server;
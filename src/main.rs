@@ -0,0 +1,566 @@
+use advent::{
+    answers,
+    bench::{self, Config},
+    calendar,
+    client::Client,
+    days, examples, io,
+    puzzle::PuzzleId,
+    render::Format,
+    scaffold,
+    solution::{Part, Runnable},
+    submit::{self, Log, Verdict},
+};
+use anyhow::{anyhow, bail, Result};
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+const USAGE: &str = "Usage:
+    advent run <day|all> [--part <1|2>] [--input <path|->]
+    advent fetch <day> [--year <year>]
+    advent submit <day> <1|2> [answer]
+    advent verify [day]
+    advent bench <day|all> [--part <1|2>] [--runs <n>] [--warmup <n>] [--format <table|json>]
+    advent examples <day> <page.html|->
+    advent new <day>
+    advent render <day> [--input <path|->] [--format <ansi|ppm|svg>] [--scale <n>]";
+
+/// How many pixels, or SVG units, each cell is drawn across by default.
+const SCALE: usize = 10;
+
+#[derive(Debug, PartialEq)]
+enum Days {
+    One(usize),
+    All,
+}
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    Run {
+        days: Days,
+        parts: Vec<Part>,
+        input: Option<PathBuf>,
+    },
+    Fetch {
+        puzzle: PuzzleId,
+    },
+    Submit {
+        day: usize,
+        part: Part,
+        answer: Option<String>,
+    },
+    Verify {
+        days: Days,
+    },
+    Examples {
+        day: usize,
+        page: PathBuf,
+    },
+    New {
+        day: usize,
+    },
+    Render {
+        day: usize,
+        input: Option<PathBuf>,
+        format: Format,
+        scale: usize,
+    },
+    Bench {
+        days: Days,
+        parts: Vec<Part>,
+        config: Config,
+        json: bool,
+    },
+}
+
+fn parse_args(args: &[String]) -> Result<Command> {
+    let [command, rest @ ..] = args else {
+        bail!("Missing command\n\n{}", USAGE);
+    };
+
+    match command.as_str() {
+        "run" => {
+            let [day, flags @ ..] = rest else {
+                bail!("Missing day\n\n{}", USAGE);
+            };
+
+            let days = match day.as_str() {
+                "all" => Days::All,
+                day => Days::One(day.parse()?),
+            };
+
+            let mut parts = Part::values().to_vec();
+            let mut input = None;
+
+            let mut flags = flags.iter();
+            while let Some(flag) = flags.next() {
+                let value =
+                    flags
+                        .next()
+                        .ok_or(anyhow!("Missing value for {}\n\n{}", flag, USAGE))?;
+
+                match flag.as_str() {
+                    "--part" => parts = vec![value.parse()?],
+                    "--input" => input = Some(PathBuf::from(value)),
+                    _ => bail!("Unexpected argument {}\n\n{}", flag, USAGE),
+                }
+            }
+
+            if input.is_some() && days == Days::All {
+                bail!("--input can only be used with a single day\n\n{}", USAGE);
+            }
+
+            Ok(Command::Run { days, parts, input })
+        }
+        "fetch" => {
+            let puzzle = match rest {
+                [day] => PuzzleId::day(day.parse()?)?,
+                [day, flag, year] if flag == "--year" => {
+                    PuzzleId::new(year.parse()?, day.parse()?)?
+                }
+                _ => bail!("Expected a day and optionally a year\n\n{}", USAGE),
+            };
+
+            Ok(Command::Fetch { puzzle })
+        }
+        "submit" => {
+            let (day, part, answer) = match rest {
+                [day, part] => (day, part, None),
+                [day, part, answer] => (day, part, Some(answer.clone())),
+                _ => bail!(
+                    "Expected a day, a part and optionally an answer\n\n{}",
+                    USAGE
+                ),
+            };
+
+            Ok(Command::Submit {
+                day: day.parse()?,
+                part: part.parse()?,
+                answer,
+            })
+        }
+        "verify" => {
+            let days = match rest {
+                [] => Days::All,
+                [day] => Days::One(day.parse()?),
+                _ => bail!("Expected at most one day\n\n{}", USAGE),
+            };
+
+            Ok(Command::Verify { days })
+        }
+        "bench" => {
+            let [day, flags @ ..] = rest else {
+                bail!("Missing day\n\n{}", USAGE);
+            };
+
+            let days = match day.as_str() {
+                "all" => Days::All,
+                day => Days::One(day.parse()?),
+            };
+
+            let mut parts = Part::values().to_vec();
+            let mut config = Config::default();
+            let mut json = false;
+
+            let mut flags = flags.iter();
+            while let Some(flag) = flags.next() {
+                let value =
+                    flags
+                        .next()
+                        .ok_or(anyhow!("Missing value for {}\n\n{}", flag, USAGE))?;
+
+                match flag.as_str() {
+                    "--part" => parts = vec![value.parse()?],
+                    "--runs" => config.runs = value.parse()?,
+                    "--warmup" => config.warmup = value.parse()?,
+                    "--format" => {
+                        json = match value.as_str() {
+                            "table" => false,
+                            "json" => true,
+                            _ => bail!("Unknown format {}\n\n{}", value, USAGE),
+                        }
+                    }
+                    _ => bail!("Unexpected argument {}\n\n{}", flag, USAGE),
+                }
+            }
+
+            if config.runs == 0 {
+                bail!("--runs must be at least 1\n\n{}", USAGE);
+            }
+
+            Ok(Command::Bench {
+                days,
+                parts,
+                config,
+                json,
+            })
+        }
+        "new" => {
+            let [day] = rest else {
+                bail!("Expected a day\n\n{}", USAGE);
+            };
+
+            Ok(Command::New { day: day.parse()? })
+        }
+        "examples" => {
+            let [day, page] = rest else {
+                bail!("Expected a day and a saved puzzle page\n\n{}", USAGE);
+            };
+
+            Ok(Command::Examples {
+                day: day.parse()?,
+                page: PathBuf::from(page),
+            })
+        }
+        "render" => {
+            let [day, flags @ ..] = rest else {
+                bail!("Missing day\n\n{}", USAGE);
+            };
+
+            let mut input = None;
+            let mut format = Format::Ansi;
+            let mut scale = SCALE;
+
+            let mut flags = flags.iter();
+            while let Some(flag) = flags.next() {
+                let value =
+                    flags
+                        .next()
+                        .ok_or(anyhow!("Missing value for {}\n\n{}", flag, USAGE))?;
+
+                match flag.as_str() {
+                    "--input" => input = Some(PathBuf::from(value)),
+                    "--format" => format = value.parse()?,
+                    "--scale" => scale = value.parse()?,
+                    _ => bail!("Unexpected argument {}\n\n{}", flag, USAGE),
+                }
+            }
+
+            if scale == 0 {
+                bail!("--scale must be at least 1\n\n{}", USAGE);
+            }
+
+            Ok(Command::Render {
+                day: day.parse()?,
+                input,
+                format,
+                scale,
+            })
+        }
+        _ => bail!("Unknown command {}\n\n{}", command, USAGE),
+    }
+}
+
+fn run_day(day: &dyn Runnable, parts: &[Part], input: Option<&Path>) -> Result<Vec<String>> {
+    let input = match input {
+        Some(path) => io::from_path(path)?,
+        None => io::for_day(day.day())?,
+    };
+    day.run(&input, parts)
+}
+
+fn main() -> Result<()> {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+
+    match parse_args(&args)? {
+        Command::Run {
+            days: Days::One(day),
+            parts,
+            input,
+        } => {
+            let day = days::get(day).ok_or(anyhow!("Invalid day: {}", day))?;
+            for answer in run_day(day, &parts, input.as_deref())? {
+                println!("{}", answer);
+            }
+        }
+        Command::Run {
+            days: Days::All,
+            parts,
+            ..
+        } => {
+            let rows = calendar::run(&days::DAYS, &parts, io::for_day);
+            print!("{}", calendar::to_table(&rows));
+        }
+        Command::Fetch { puzzle } => {
+            io::for_puzzle(puzzle)?;
+            let path = io::locate(puzzle).unwrap_or(io::input_path(puzzle));
+            println!("{}", path.display());
+        }
+        Command::Submit { day, part, answer } => {
+            let answer = match answer {
+                Some(answer) => answer,
+                None => {
+                    let solution = days::get(day).ok_or(anyhow!("Invalid day: {}", day))?;
+                    run_day(solution, &[part], None)?.remove(0)
+                }
+            };
+
+            let puzzle = PuzzleId::day(day)?;
+            let client = Client::from_env()?;
+            let mut log = Log::open(Log::path_for(puzzle))?;
+            let verdict = submit::submit(&client, &mut log, puzzle, part, &answer)?;
+            println!("Day {} part {}: {} is {}", day, part, answer, verdict);
+
+            if verdict == Verdict::Correct {
+                answers::record(day, part, &answer)?;
+            }
+        }
+        Command::Verify { days } => {
+            let days = match days {
+                Days::One(day) => vec![days::get(day).ok_or(anyhow!("Invalid day: {}", day))?],
+                Days::All => days::DAYS.to_vec(),
+            };
+
+            let mut failures = 0;
+            for day in days {
+                for check in answers::verify(day)? {
+                    if check.passed() {
+                        println!("Day {} part {}: ok", check.day, check.part);
+                    } else {
+                        failures += 1;
+                        println!(
+                            "Day {} part {}: FAILED, expected {} but got {}",
+                            check.day, check.part, check.expected, check.actual
+                        );
+                    }
+                }
+            }
+
+            if failures > 0 {
+                bail!("{} answers changed", failures);
+            }
+        }
+        Command::Bench {
+            days,
+            parts,
+            config,
+            json,
+        } => {
+            let mut timings = Vec::new();
+            match days {
+                Days::One(day) => {
+                    let day = days::get(day).ok_or(anyhow!("Invalid day: {}", day))?;
+                    timings.extend(day.bench(&io::for_day(day.day())?, &parts, &config)?);
+                }
+                Days::All => {
+                    // only the parts with a known answer are solved, and worth tracking
+                    for day in days::DAYS {
+                        let mut solved = Vec::new();
+                        for &part in &parts {
+                            if answers::expected(day.day(), part)?.is_some() {
+                                solved.push(part);
+                            }
+                        }
+
+                        if !solved.is_empty() {
+                            let input = io::for_day(day.day())?;
+                            timings.extend(day.bench(&input, &solved, &config)?);
+                        }
+                    }
+                }
+            }
+
+            if json {
+                println!("{}", bench::to_json(&timings));
+            } else {
+                print!("{}", bench::to_table(&timings));
+            }
+        }
+        Command::New { day } => {
+            let path = scaffold::create(&scaffold::days_dir(), day)?;
+            println!("{}", path.display());
+
+            // a missing input shouldn't stop the day being started
+            if let Err(e) = io::for_day(day) {
+                println!("Day {}: {}", day, e);
+            }
+        }
+        Command::Examples { day, page } => {
+            let examples = examples::extract(&io::from_path(&page)?)?;
+            if examples.is_empty() {
+                bail!("No examples with answers found in {}", page.display());
+            }
+
+            let dir = io::examples_dir(PuzzleId::day(day)?);
+            for path in examples::write(&dir, &examples)? {
+                println!("{}", path.display());
+            }
+        }
+        Command::Render {
+            day,
+            input,
+            format,
+            scale,
+        } => {
+            let input = match input {
+                Some(path) => io::from_path(&path)?,
+                None => io::for_day(day)?,
+            };
+            std::io::stdout().write_all(&days::render(day, &input, format, scale)?)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_ascii_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_parse_args() -> Result<()> {
+        assert_eq!(
+            parse_args(&args("run 7"))?,
+            Command::Run {
+                days: Days::One(7),
+                parts: vec![Part::One, Part::Two],
+                input: None
+            }
+        );
+        assert_eq!(
+            parse_args(&args("run 7 --part 2"))?,
+            Command::Run {
+                days: Days::One(7),
+                parts: vec![Part::Two],
+                input: None
+            }
+        );
+        assert_eq!(
+            parse_args(&args("run 7 --input - --part 1"))?,
+            Command::Run {
+                days: Days::One(7),
+                parts: vec![Part::One],
+                input: Some(PathBuf::from("-"))
+            }
+        );
+        assert_eq!(
+            parse_args(&args("run all"))?,
+            Command::Run {
+                days: Days::All,
+                parts: vec![Part::One, Part::Two],
+                input: None
+            }
+        );
+        assert_eq!(
+            parse_args(&args("fetch 12"))?,
+            Command::Fetch {
+                puzzle: PuzzleId::new(2023, 12)?
+            }
+        );
+        assert_eq!(
+            parse_args(&args("fetch 3 --year 2015"))?,
+            Command::Fetch {
+                puzzle: PuzzleId::new(2015, 3)?
+            }
+        );
+        assert_eq!(
+            parse_args(&args("submit 7 2"))?,
+            Command::Submit {
+                day: 7,
+                part: Part::Two,
+                answer: None
+            }
+        );
+        assert_eq!(
+            parse_args(&args("submit 7 1 6440"))?,
+            Command::Submit {
+                day: 7,
+                part: Part::One,
+                answer: Some("6440".to_string())
+            }
+        );
+        assert_eq!(
+            parse_args(&args("verify"))?,
+            Command::Verify { days: Days::All }
+        );
+        assert_eq!(
+            parse_args(&args("verify 7"))?,
+            Command::Verify { days: Days::One(7) }
+        );
+        assert_eq!(
+            parse_args(&args("bench 7 --runs 50 --format json --part 2"))?,
+            Command::Bench {
+                days: Days::One(7),
+                parts: vec![Part::Two],
+                config: Config {
+                    warmup: Config::default().warmup,
+                    runs: 50
+                },
+                json: true
+            }
+        );
+        assert_eq!(
+            parse_args(&args("bench all --warmup 0"))?,
+            Command::Bench {
+                days: Days::All,
+                parts: vec![Part::One, Part::Two],
+                config: Config {
+                    warmup: 0,
+                    runs: Config::default().runs
+                },
+                json: false
+            }
+        );
+        assert_eq!(parse_args(&args("new 17"))?, Command::New { day: 17 });
+        assert_eq!(
+            parse_args(&args("render 16"))?,
+            Command::Render {
+                day: 16,
+                input: None,
+                format: Format::Ansi,
+                scale: SCALE
+            }
+        );
+        assert_eq!(
+            parse_args(&args("render 10 --format svg --scale 4 --input -"))?,
+            Command::Render {
+                day: 10,
+                input: Some(PathBuf::from("-")),
+                format: Format::Svg,
+                scale: 4
+            }
+        );
+        assert_eq!(
+            parse_args(&args("examples 9 day9.html"))?,
+            Command::Examples {
+                day: 9,
+                page: PathBuf::from("day9.html")
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_args_invalid() {
+        assert!(parse_args(&args("")).is_err());
+        assert!(parse_args(&args("walk 7")).is_err());
+        assert!(parse_args(&args("run")).is_err());
+        assert!(parse_args(&args("run seven")).is_err());
+        assert!(parse_args(&args("run 7 --part 3")).is_err());
+        assert!(parse_args(&args("run 7 --verbose")).is_err());
+        assert!(parse_args(&args("run 7 --input")).is_err());
+        assert!(parse_args(&args("run all --input friend.txt")).is_err());
+        assert!(parse_args(&args("fetch")).is_err());
+        assert!(parse_args(&args("fetch 1 2")).is_err());
+        assert!(parse_args(&args("fetch 26")).is_err());
+        assert!(parse_args(&args("fetch 1 --year 2014")).is_err());
+        assert!(parse_args(&args("submit 7")).is_err());
+        assert!(parse_args(&args("submit 7 3")).is_err());
+        assert!(parse_args(&args("verify 7 8")).is_err());
+        assert!(parse_args(&args("examples 9")).is_err());
+        assert!(parse_args(&args("new")).is_err());
+        assert!(parse_args(&args("new 17 18")).is_err());
+        assert!(parse_args(&args("bench")).is_err());
+        assert!(parse_args(&args("bench 7 --runs 0")).is_err());
+        assert!(parse_args(&args("bench 7 --format csv")).is_err());
+        assert!(parse_args(&args("bench 7 --json")).is_err());
+        assert!(parse_args(&args("render")).is_err());
+        assert!(parse_args(&args("render 10 --format png")).is_err());
+        assert!(parse_args(&args("render 10 --scale 0")).is_err());
+    }
+}
@@ -0,0 +1,163 @@
+//! Number theory for lining up things that repeat: gcd and lcm over whole
+//! lists, extended Euclid, modular inverses and the Chinese remainder theorem.
+//!
+//! Anything that could overflow returns `None` instead of wrapping.
+
+/// `None` only when the answer is 2^63, from `i64::MIN` and 0 or itself.
+pub fn gcd(a: i64, b: i64) -> Option<i64> {
+    let (mut a, mut b) = (a.unsigned_abs(), b.unsigned_abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a.try_into().ok()
+}
+
+pub fn lcm(a: i64, b: i64) -> Option<i64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)?).checked_mul(b)?.checked_abs()
+}
+
+/// The gcd of every value, or 0 if there are none.
+pub fn gcd_all(values: impl IntoIterator<Item = i64>) -> Option<i64> {
+    values.into_iter().try_fold(0, gcd)
+}
+
+/// The lcm of every value, or 1 if there are none.
+pub fn lcm_all(values: impl IntoIterator<Item = i64>) -> Option<i64> {
+    values.into_iter().try_fold(1, lcm)
+}
+
+/// `(g, x, y)` such that `a * x + b * y == g`, where `g` is the gcd of `a` and `b`.
+pub fn extended_gcd(a: i64, b: i64) -> Option<(i64, i64, i64)> {
+    // i128 leaves room for negating i64::MIN and for the quotients on the way
+    let (mut r0, mut r1) = (a as i128, b as i128);
+    let (mut x0, mut x1) = (1, 0);
+    let (mut y0, mut y1) = (0, 1);
+
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+
+    if r0 < 0 {
+        (r0, x0, y0) = (-r0, -x0, -y0);
+    }
+    Some((
+        r0.try_into().ok()?,
+        x0.try_into().ok()?,
+        y0.try_into().ok()?,
+    ))
+}
+
+/// The `x` in `0..modulus` with `a * x ≡ 1`, if `a` and `modulus` are coprime.
+pub fn mod_inverse(a: i64, modulus: i64) -> Option<i64> {
+    if modulus <= 0 {
+        return None;
+    }
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus)?;
+    (g == 1).then(|| x.rem_euclid(modulus))
+}
+
+/// Solves `x ≡ residue (mod modulus)` for every pair at once, returning the
+/// smallest non-negative `x` and the lcm of the moduli, after which the
+/// solutions repeat. The moduli don't have to be coprime, but if they share
+/// factors the residues have to agree on them, otherwise there is no solution.
+pub fn crt(congruences: impl IntoIterator<Item = (i64, i64)>) -> Option<(i64, i64)> {
+    congruences.into_iter().try_fold((0, 1), merge)
+}
+
+fn merge((a1, m1): (i64, i64), (a2, m2): (i64, i64)) -> Option<(i64, i64)> {
+    if m1 <= 0 || m2 <= 0 {
+        return None;
+    }
+
+    let (g, p, _) = extended_gcd(m1, m2)?;
+    let difference = a2 as i128 - a1 as i128;
+    if difference % g as i128 != 0 {
+        return None;
+    }
+
+    // step from a1 in multiples of m1 until we also land on a2 (mod m2)
+    let (m1, m2, g) = (m1 as i128, m2 as i128, g as i128);
+    let steps = (difference / g * p as i128).rem_euclid(m2 / g);
+    let modulus = m1 / g * m2;
+    let x = (a1 as i128 + steps * m1).rem_euclid(modulus);
+
+    Some((x.try_into().ok()?, modulus.try_into().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12, 18), Some(6));
+        assert_eq!(gcd(-12, 18), Some(6));
+        assert_eq!(gcd(0, 5), Some(5));
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(gcd_all([12, 18, 27]), Some(3));
+        assert_eq!(lcm_all([2, 3, 4]), Some(12));
+        assert_eq!(lcm_all([]), Some(1));
+        assert_eq!(lcm_all([i64::MAX, i64::MAX - 1]), None);
+    }
+
+    #[test]
+    fn test_min() {
+        assert_eq!(gcd(i64::MIN, 6), Some(2));
+        assert_eq!(gcd(i64::MIN, 0), None);
+        assert_eq!(gcd(i64::MIN, i64::MIN), None);
+        assert_eq!(lcm(i64::MIN, 1), None);
+        assert_eq!(lcm(i64::MIN / 2, 2), Some(1 << 62));
+        assert_eq!(extended_gcd(i64::MIN, 0), None);
+        assert_eq!(extended_gcd(i64::MIN, -1), Some((1, 0, -1)));
+        assert_eq!(mod_inverse(i64::MIN, 3), Some(1));
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(6, 9), None);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // the moduli share a factor of 2, and the residues agree on it
+        assert_eq!(crt([(3, 4), (1, 6)]), Some((7, 12)));
+        // but here they don't
+        assert_eq!(crt([(0, 4), (1, 6)]), None);
+        assert_eq!(crt([]), Some((0, 1)));
+    }
+
+    #[quickcheck_macros::quickcheck]
+    fn test_extended_gcd(a: i32, b: i32) -> bool {
+        let (a, b) = (a as i64, b as i64);
+        let (g, x, y) = extended_gcd(a, b).unwrap();
+        Some(g) == gcd(a, b) && a * x + b * y == g
+    }
+
+    #[quickcheck_macros::quickcheck]
+    fn test_crt_matches_brute_force(congruences: Vec<(u8, u8)>) -> bool {
+        let congruences = congruences
+            .into_iter()
+            .take(4)
+            .map(|(residue, modulus)| {
+                let modulus = modulus as i64 % 12 + 1;
+                (residue as i64 % modulus, modulus)
+            })
+            .collect::<Vec<_>>();
+
+        let period = lcm_all(congruences.iter().map(|&(_, m)| m)).unwrap();
+        let expected = (0..period)
+            .find(|x| congruences.iter().all(|&(r, m)| x % m == r))
+            .map(|x| (x, period));
+
+        crt(congruences) == expected
+    }
+}
@@ -0,0 +1,355 @@
+//! Helpers for the shapes puzzle inputs come in: blank line separated
+//! sections, labelled number lists, `key: value` lines and grids.
+//!
+//! Everything works on [`Text`], a slice that remembers where it came from,
+//! so a failure can say which line and column of the input was wrong.
+
+use std::{fmt::Display, str::FromStr};
+
+use crate::grid::Grid;
+
+/// Why part of an input could not be parsed, and where.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ParseError {
+    /// 1 based, like an editor.
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {} in `{}`",
+            self.line, self.column, self.message, self.text
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub type Result<T, E = ParseError> = std::result::Result<T, E>;
+
+/// A piece of an input, along with the whole input it was cut from.
+#[derive(Debug, Clone, Copy)]
+pub struct Text<'a> {
+    source: &'a str,
+    text: &'a str,
+}
+
+impl<'a> Text<'a> {
+    pub fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            text: source,
+        }
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    // `text` always points into `source`, so the distance between them is
+    // how far into the input this piece starts
+    fn offset(&self) -> usize {
+        self.text.as_ptr() as usize - self.source.as_ptr() as usize
+    }
+
+    fn slice(&self, text: &'a str) -> Self {
+        Self {
+            source: self.source,
+            text,
+        }
+    }
+
+    /// The line and column this piece starts at.
+    pub fn position(&self) -> (usize, usize) {
+        let before = &self.source[..self.offset()];
+        let line = before.matches('\n').count() + 1;
+        let column = before
+            .rsplit('\n')
+            .next()
+            .map_or(0, |start| start.chars().count())
+            + 1;
+        (line, column)
+    }
+
+    pub fn error(&self, message: impl Display) -> ParseError {
+        let (line, column) = self.position();
+        ParseError {
+            line,
+            column,
+            text: self.text.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    pub fn trim(&self) -> Self {
+        self.slice(self.text.trim())
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = Text<'a>> + 'a {
+        let this = *self;
+        self.text.lines().map(move |line| this.slice(line))
+    }
+
+    /// The groups of lines separated by blank lines.
+    pub fn sections(&self) -> impl Iterator<Item = Text<'a>> + 'a {
+        let this = *self;
+        self.text
+            .split("\n\n")
+            .map(move |section| this.slice(section.trim_matches('\n')))
+            .filter(|section| !section.is_empty())
+    }
+
+    pub fn words(&self) -> impl Iterator<Item = Text<'a>> + 'a {
+        let this = *self;
+        self.text
+            .split_ascii_whitespace()
+            .map(move |word| this.slice(word))
+    }
+
+    pub fn strip_prefix(&self, prefix: &str) -> Result<Self> {
+        self.text
+            .strip_prefix(prefix)
+            .map(|rest| self.slice(rest))
+            .ok_or_else(|| self.error(format!("expected `{}`", prefix)))
+    }
+
+    pub fn strip_suffix(&self, suffix: &str) -> Result<Self> {
+        self.text
+            .strip_suffix(suffix)
+            .map(|rest| self.slice(rest))
+            .ok_or_else(|| self.error(format!("expected it to end with `{}`", suffix)))
+    }
+
+    pub fn split_once(&self, separator: &str) -> Result<(Self, Self)> {
+        let (before, after) = self
+            .text
+            .split_once(separator)
+            .ok_or_else(|| self.error(format!("expected `{}`", separator)))?;
+        Ok((self.slice(before), self.slice(after)))
+    }
+
+    pub fn split<'s>(&self, separator: &'s str) -> impl Iterator<Item = Text<'a>> + 's
+    where
+        'a: 's,
+    {
+        let this = *self;
+        self.text
+            .split(separator)
+            .map(move |piece| this.slice(piece))
+    }
+
+    /// Splits into exactly `N` pieces.
+    pub fn split_n<const N: usize>(&self, separator: &str) -> Result<[Self; N]> {
+        let pieces = self.split(separator).collect::<Vec<_>>();
+        let found = pieces.len();
+
+        pieces.try_into().map_err(|_| {
+            self.error(format!(
+                "expected {} parts separated by `{}`, found {}",
+                N, separator, found
+            ))
+        })
+    }
+
+    /// A `key<separator>value` line, with both sides trimmed.
+    pub fn key_value(&self, separator: &str) -> Result<(Self, Self)> {
+        let (key, value) = self.split_once(separator)?;
+        Ok((key.trim(), value.trim()))
+    }
+
+    pub fn parse<T>(&self) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.text.trim().parse().map_err(|e| self.trim().error(e))
+    }
+
+    /// Whitespace separated values, failing on the first one that doesn't parse.
+    pub fn numbers<T>(&self) -> Result<Vec<T>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.words().map(|word| word.parse()).collect()
+    }
+
+    /// A list of numbers after a label, like `Time:      7  15   30`.
+    pub fn labelled<T>(&self, label: &str) -> Result<Vec<T>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.trim().strip_prefix(label)?.numbers()
+    }
+
+    /// Each character converted with `T::try_from`.
+    pub fn chars<T>(&self) -> Result<Vec<T>>
+    where
+        T: TryFrom<char>,
+        T::Error: Display,
+    {
+        self.text
+            .char_indices()
+            .map(|(i, c)| {
+                T::try_from(c).map_err(|e| self.slice(&self.text[i..i + c.len_utf8()]).error(e))
+            })
+            .collect()
+    }
+
+    /// One row per line, each character converted with `T::try_from`.
+    pub fn grid<T>(&self) -> Result<Grid<T>>
+    where
+        T: TryFrom<char>,
+        T::Error: Display,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut data = Vec::new();
+
+        for line in self.lines() {
+            let cells = line.chars::<T>()?;
+            let length = cells.len();
+            data.extend(cells);
+
+            match width {
+                None => width = Some(length),
+                Some(width) if width != length => {
+                    return Err(line.error(format!(
+                        "expected {} cells like the rows above, found {}",
+                        width, length
+                    )))
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        Grid::new(width.unwrap_or(0), height, data).map_err(|e| self.error(e))
+    }
+}
+
+impl Display for Text<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 x8";
+
+    #[test]
+    fn test_sections() {
+        let sections = Text::new(INPUT)
+            .sections()
+            .map(|s| (s.as_str(), s.position()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            sections,
+            vec![
+                ("seeds: 79 14 55 13", (1, 1)),
+                ("seed-to-soil map:\n50 98 2\n52 50 x8", (3, 1)),
+            ]
+        );
+        assert_eq!(Text::new("\n\na\n\n\n\nb\n").sections().count(), 2);
+    }
+
+    #[test]
+    fn test_labelled() -> Result<()> {
+        let seeds = Text::new(INPUT).lines().next().unwrap();
+        assert_eq!(seeds.labelled::<u32>("seeds:")?, vec![79, 14, 55, 13]);
+
+        let error = seeds.labelled::<u32>("Time:").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+        assert_eq!(error.message, "expected `Time:`");
+        Ok(())
+    }
+
+    #[test]
+    fn test_numbers_report_position() {
+        let last = Text::new(INPUT).lines().last().unwrap();
+        let error = last.numbers::<u32>().unwrap_err();
+
+        assert_eq!(
+            error,
+            ParseError {
+                line: 5,
+                column: 7,
+                text: "x8".to_string(),
+                message: "invalid digit found in string".to_string(),
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "line 5, column 7: invalid digit found in string in `x8`"
+        );
+    }
+
+    #[test]
+    fn test_splitting() -> Result<()> {
+        let header = Text::new(INPUT)
+            .sections()
+            .nth(1)
+            .unwrap()
+            .lines()
+            .next()
+            .unwrap();
+        let [source, _, dest] = header.strip_suffix(" map:")?.split_n("-")?;
+        assert_eq!((source.as_str(), dest.as_str()), ("seed", "soil"));
+        assert_eq!(dest.position(), (3, 9));
+
+        let error = header.split_n::<2>("-").unwrap_err();
+        assert_eq!(error.message, "expected 2 parts separated by `-`, found 3");
+
+        let (key, value) = Text::new("AAA = (BBB, CCC)").key_value("=")?;
+        assert_eq!((key.as_str(), value.as_str()), ("AAA", "(BBB, CCC)"));
+        assert!(Text::new("AAA").key_value("=").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_grid() -> Result<()> {
+        #[derive(Debug, PartialEq)]
+        struct Rock(bool);
+
+        impl TryFrom<char> for Rock {
+            type Error = String;
+
+            fn try_from(c: char) -> Result<Self, String> {
+                match c {
+                    '#' => Ok(Rock(true)),
+                    '.' => Ok(Rock(false)),
+                    _ => Err(format!("unknown tile {:?}", c)),
+                }
+            }
+        }
+
+        let grid = Text::new("#.\n.#").grid::<Rock>()?;
+        assert_eq!(grid.get(1, 1), Some(&Rock(true)));
+
+        let error = Text::new("#.\n.?").grid::<Rock>().unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.text, "?");
+
+        let error = Text::new("#.\n.").grid::<Rock>().unwrap_err();
+        assert_eq!(error.line, 2);
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+use std::fmt::Display;
+
+use crate::io::InputError;
+
+/// The year the solutions in `days` are for.
+pub const YEAR: usize = 2023;
+
+/// Advent of Code started in 2015.
+pub const FIRST_YEAR: usize = 2015;
+
+/// How many puzzles there are in a given December.
+pub fn days_in(year: usize) -> usize {
+    // the calendar was cut down to twelve days from 2025
+    if year >= 2025 {
+        12
+    } else {
+        25
+    }
+}
+
+/// A single puzzle, identified by the year and day it was released.
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Copy, Clone)]
+pub struct PuzzleId {
+    pub year: usize,
+    pub day: usize,
+}
+
+impl PuzzleId {
+    pub fn new(year: usize, day: usize) -> Result<Self, InputError> {
+        if year < FIRST_YEAR || day == 0 || day > days_in(year) {
+            return Err(InputError::InvalidDay { year, day });
+        }
+
+        Ok(Self { year, day })
+    }
+
+    /// A day of the year these solutions are for.
+    pub fn day(day: usize) -> Result<Self, InputError> {
+        Self::new(YEAR, day)
+    }
+}
+
+impl Display for PuzzleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} day {}", self.year, self.day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        assert!(PuzzleId::new(2015, 1).is_ok());
+        assert!(PuzzleId::new(2023, 25).is_ok());
+        assert!(PuzzleId::new(2025, 12).is_ok());
+
+        assert!(PuzzleId::new(2014, 1).is_err());
+        assert!(PuzzleId::new(2023, 0).is_err());
+        assert!(PuzzleId::new(2023, 26).is_err());
+        assert!(PuzzleId::new(2025, 13).is_err());
+    }
+
+    #[test]
+    fn test_display() -> Result<(), InputError> {
+        assert_eq!(PuzzleId::day(7)?.to_string(), "2023 day 7");
+        Ok(())
+    }
+}
@@ -0,0 +1,344 @@
+//! Drawing a [`Grid`] for debugging, either straight to a terminal with ANSI
+//! colours or to a PPM or SVG image, with paths and sets of cells picked out.
+
+use std::{collections::HashMap, fmt::Write, str::FromStr};
+
+use anyhow::{bail, Error, Result};
+
+use crate::grid::Grid;
+
+/// Which kind of output to draw.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Format {
+    Ansi,
+    Ppm,
+    Svg,
+}
+
+impl FromStr for Format {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "ansi" => Ok(Self::Ansi),
+            "ppm" => Ok(Self::Ppm),
+            "svg" => Ok(Self::Svg),
+            _ => bail!("Unknown format {} (expected ansi, ppm or svg)", s),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Colour {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Colour {
+    pub const BLACK: Self = Self::new(0, 0, 0);
+    pub const WHITE: Self = Self::new(255, 255, 255);
+    pub const GREY: Self = Self::new(128, 128, 128);
+    pub const RED: Self = Self::new(220, 50, 47);
+    pub const GREEN: Self = Self::new(133, 153, 0);
+    pub const BLUE: Self = Self::new(38, 139, 210);
+    pub const YELLOW: Self = Self::new(181, 137, 0);
+
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    fn hex(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+/// How to draw one cell.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Style {
+    pub glyph: char,
+    pub foreground: Colour,
+    pub background: Option<Colour>,
+}
+
+impl Style {
+    pub fn new(glyph: char) -> Self {
+        Self {
+            glyph,
+            foreground: Colour::WHITE,
+            background: None,
+        }
+    }
+
+    pub fn with_foreground(mut self, colour: Colour) -> Self {
+        self.foreground = colour;
+        self
+    }
+
+    pub fn with_background(mut self, colour: Colour) -> Self {
+        self.background = Some(colour);
+        self
+    }
+
+    // images have no room for the glyph, so a cell is just the one colour
+    fn fill(&self) -> Colour {
+        self.background.unwrap_or(self.foreground)
+    }
+}
+
+/// A grid, how to style each kind of cell, and anything drawn on top.
+pub struct Renderer<'a, T> {
+    grid: &'a Grid<T>,
+    style: Box<dyn Fn(&T) -> Style + 'a>,
+    // later overlays win where they cross
+    highlights: HashMap<(usize, usize), Colour>,
+    paths: Vec<(Vec<(usize, usize)>, Colour)>,
+}
+
+impl<'a, T> Renderer<'a, T> {
+    pub fn new(grid: &'a Grid<T>, style: impl Fn(&T) -> Style + 'a) -> Self {
+        Self {
+            grid,
+            style: Box::new(style),
+            highlights: HashMap::new(),
+            paths: Vec::new(),
+        }
+    }
+
+    /// Colours the background of every cell in `cells`, like the tiles a
+    /// search has visited. Cells are `(row, column)` pairs or points.
+    pub fn with_highlight(
+        mut self,
+        cells: impl IntoIterator<Item = impl Into<(usize, usize)>>,
+        colour: Colour,
+    ) -> Self {
+        for cell in cells {
+            self.highlights.insert(cell.into(), colour);
+        }
+        self
+    }
+
+    /// Highlights the cells of `path`, and in an SVG also joins them up in
+    /// order.
+    pub fn with_path(
+        mut self,
+        path: impl IntoIterator<Item = impl Into<(usize, usize)>>,
+        colour: Colour,
+    ) -> Self {
+        let path = path.into_iter().map(Into::into).collect::<Vec<_>>();
+        self = self.with_highlight(path.iter().copied(), colour);
+        self.paths.push((path, colour));
+        self
+    }
+
+    fn style(&self, position: (usize, usize), value: &T) -> Style {
+        let style = (self.style)(value);
+        match self.highlights.get(&position) {
+            Some(&colour) => style.with_background(colour),
+            None => style,
+        }
+    }
+
+    /// Draws in any [`Format`], with `scale` ignored for ANSI.
+    pub fn to_bytes(&self, format: Format, scale: usize) -> Vec<u8> {
+        match format {
+            Format::Ansi => self.to_ansi().into_bytes(),
+            Format::Ppm => self.to_ppm(scale),
+            Format::Svg => self.to_svg(scale).into_bytes(),
+        }
+    }
+
+    /// One line per row, coloured with 24 bit ANSI escape codes.
+    pub fn to_ansi(&self) -> String {
+        let mut out = String::new();
+
+        for (row, cells) in self.grid.rows().enumerate() {
+            for (column, value) in cells.iter().enumerate() {
+                let style = self.style((row, column), value);
+                let Colour { r, g, b } = style.foreground;
+                let _ = write!(out, "\x1b[38;2;{};{};{}m", r, g, b);
+                if let Some(Colour { r, g, b }) = style.background {
+                    let _ = write!(out, "\x1b[48;2;{};{};{}m", r, g, b);
+                }
+                out.push(style.glyph);
+                out.push_str("\x1b[0m");
+            }
+            out.push('\n');
+        }
+
+        out
+    }
+
+    /// A binary PPM with each cell drawn as a `scale` by `scale` square.
+    pub fn to_ppm(&self, scale: usize) -> Vec<u8> {
+        let (width, height) = (self.grid.width() * scale, self.grid.height() * scale);
+        let mut out = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+
+        for (row, cells) in self.grid.rows().enumerate() {
+            let line = cells
+                .iter()
+                .enumerate()
+                .flat_map(|(column, value)| {
+                    let Colour { r, g, b } = self.style((row, column), value).fill();
+                    [r, g, b].repeat(scale)
+                })
+                .collect::<Vec<_>>();
+            for _ in 0..scale {
+                out.extend_from_slice(&line);
+            }
+        }
+
+        out
+    }
+
+    /// An SVG with each cell `scale` units across.
+    pub fn to_svg(&self, scale: usize) -> String {
+        let (width, height) = (self.grid.width() * scale, self.grid.height() * scale);
+        let mut out = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\" font-family=\"monospace\" font-size=\"{s}\" text-anchor=\"middle\" dominant-baseline=\"central\">\n",
+            w = width,
+            h = height,
+            s = (scale * 4 / 5).max(1),
+        );
+        let centre = |i: usize| i * scale + scale / 2;
+
+        for ((row, column), value) in self.grid.cells() {
+            let style = self.style((row, column), value);
+            if let Some(colour) = style.background {
+                let _ = writeln!(
+                    out,
+                    "<rect x=\"{}\" y=\"{}\" width=\"{s}\" height=\"{s}\" fill=\"{}\"/>",
+                    column * scale,
+                    row * scale,
+                    colour.hex(),
+                    s = scale,
+                );
+            }
+            if !style.glyph.is_whitespace() {
+                let _ = writeln!(
+                    out,
+                    "<text x=\"{}\" y=\"{}\" fill=\"{}\">{}</text>",
+                    centre(column),
+                    centre(row),
+                    style.foreground.hex(),
+                    escape(style.glyph),
+                );
+            }
+        }
+
+        for (path, colour) in &self.paths {
+            let points = path
+                .iter()
+                .map(|&(row, column)| format!("{},{}", centre(column), centre(row)))
+                .collect::<Vec<_>>()
+                .join(" ");
+            let _ = writeln!(
+                out,
+                "<polyline points=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"{}\"/>",
+                points,
+                colour.hex(),
+                (scale / 5).max(1),
+            );
+        }
+
+        out.push_str("</svg>\n");
+        out
+    }
+}
+
+fn escape(c: char) -> String {
+    match c {
+        '<' => "&lt;".to_string(),
+        '>' => "&gt;".to_string(),
+        '&' => "&amp;".to_string(),
+        '"' => "&quot;".to_string(),
+        _ => c.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        "#.\n.#".parse().unwrap()
+    }
+
+    fn style(c: &char) -> Style {
+        match c {
+            '#' => Style::new('#').with_foreground(Colour::WHITE),
+            _ => Style::new('.').with_foreground(Colour::BLACK),
+        }
+    }
+
+    #[test]
+    fn test_ansi() {
+        let grid = grid();
+        let ansi = Renderer::new(&grid, style)
+            .with_highlight([(1, 0)], Colour::RED)
+            .to_ansi();
+
+        let lines = ansi.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(
+            lines[1],
+            "\x1b[38;2;0;0;0m\x1b[48;2;220;50;47m.\x1b[0m\x1b[38;2;255;255;255m#\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn test_ppm() {
+        let grid = grid();
+        let ppm = Renderer::new(&grid, style)
+            .with_path([(0, 1)], Colour::BLUE)
+            .to_ppm(2);
+
+        let header = b"P6\n4 4\n255\n";
+        assert_eq!(&ppm[..header.len()], header);
+
+        let pixels = ppm[header.len()..].chunks(3).collect::<Vec<_>>();
+        assert_eq!(pixels.len(), 16);
+        // the top row of pixels is two white then two blue
+        assert_eq!(pixels[0], [255, 255, 255]);
+        assert_eq!(pixels[2], [38, 139, 210]);
+        // and the cells are two pixels tall
+        assert_eq!(pixels[4..8], pixels[0..4]);
+        assert_eq!(pixels[15], [255, 255, 255]);
+    }
+
+    #[test]
+    fn test_formats() -> Result<()> {
+        let grid = grid();
+        let renderer = Renderer::new(&grid, style);
+
+        assert_eq!("ppm".parse::<Format>()?, Format::Ppm);
+        assert!("png".parse::<Format>().is_err());
+        assert_eq!(
+            renderer.to_bytes(Format::Ansi, 3),
+            renderer.to_ansi().into_bytes()
+        );
+        assert_eq!(renderer.to_bytes(Format::Ppm, 3), renderer.to_ppm(3));
+        Ok(())
+    }
+
+    #[test]
+    fn test_svg() {
+        let grid: Grid<char> = "<.\n..".parse().unwrap();
+        let svg = Renderer::new(&grid, |&c| Style::new(c))
+            .with_path([(0, 0), (0, 1), (1, 1)], Colour::GREEN)
+            .to_svg(10);
+
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.ends_with("</svg>\n"));
+        assert!(svg.contains("<text x=\"5\" y=\"5\" fill=\"#ffffff\">&lt;</text>"));
+        assert!(
+            svg.contains("<rect x=\"10\" y=\"10\" width=\"10\" height=\"10\" fill=\"#859900\"/>")
+        );
+        assert!(svg.contains("<polyline points=\"5,5 15,5 15,15\""));
+        assert_eq!(svg.matches("<rect").count(), 3);
+
+        // the text is still drawn when the cells are a single pixel
+        let svg = Renderer::new(&grid, |&c| Style::new(c)).to_svg(1);
+        assert!(svg.contains("font-size=\"1\""));
+    }
+}
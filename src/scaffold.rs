@@ -0,0 +1,242 @@
+use std::{
+    fs::{read_to_string, write},
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, bail, Result};
+use regex::Regex;
+
+use crate::puzzle::PuzzleId;
+
+const TEMPLATE: &str = r#"use crate::solution::Solution;
+use anyhow::Result;
+
+pub struct DayNN;
+
+impl Solution for DayNN {
+    const DAY: usize = N;
+
+    type Input = String;
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        part2(input)
+    }
+}
+
+fn part1(_input: &str) -> Result<String> {
+    unimplemented!("You have to solve the puzzle first!")
+}
+
+fn part2(_input: &str) -> Result<String> {
+    unimplemented!("You have to solve the puzzle first!")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{examples, solution::Part};
+
+    #[test]
+    #[ignore]
+    fn test_part1() -> Result<()> {
+        let checks = examples::verify(&DayNN, &[Part::One])?;
+        assert!(!checks.is_empty(), "no examples saved for part 1");
+        for check in checks {
+            assert_eq!(check.actual, check.expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[ignore]
+    fn test_part2() -> Result<()> {
+        let checks = examples::verify(&DayNN, &[Part::Two])?;
+        assert!(!checks.is_empty(), "no examples saved for part 2");
+        for check in checks {
+            assert_eq!(check.actual, check.expected);
+        }
+        Ok(())
+    }
+}
+"#;
+
+/// Where the module for `day` lives in the crate.
+pub fn days_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("src/days")
+}
+
+/// The unsolved module for `day`, with both parts left `unimplemented!`.
+pub fn render(day: usize) -> String {
+    TEMPLATE
+        .replace("DayNN", &format!("Day{:02}", day))
+        .replace("DAY: usize = N;", &format!("DAY: usize = {};", day))
+}
+
+/// Adds `day` to the module list and the `DAYS` registry in `days/mod.rs`.
+pub fn register(registry: &str, day: usize) -> Result<String> {
+    let modules = Regex::new(r"(?m)^pub mod day(\d+);\n")?;
+    let array = Regex::new(r"(?s)pub static DAYS: \[&dyn Runnable; \d+\] = \[.*?\];")?;
+
+    let mut days = modules
+        .captures_iter(registry)
+        .map(|c| c[1].parse::<usize>())
+        .collect::<Result<Vec<_>, _>>()?;
+    if days.contains(&day) {
+        return Ok(registry.to_string());
+    }
+    days.push(day);
+    days.sort();
+
+    let first = modules
+        .find(registry)
+        .ok_or(anyhow!("No day modules found in the registry"))?
+        .start();
+    if !array.is_match(registry) {
+        bail!("No DAYS array found in the registry");
+    }
+
+    let declarations = days
+        .iter()
+        .map(|d| format!("pub mod day{:02};\n", d))
+        .collect::<String>();
+    let entries = days
+        .iter()
+        .map(|d| format!("    &day{:02}::Day{:02},\n", d, d))
+        .collect::<String>();
+
+    let mut registry = modules.replace_all(registry, "").to_string();
+    registry.insert_str(first, &declarations);
+
+    Ok(array
+        .replace(
+            &registry,
+            format!(
+                "pub static DAYS: [&dyn Runnable; {}] = [\n{}];",
+                days.len(),
+                entries
+            ),
+        )
+        .to_string())
+}
+
+/// Writes a fresh module for `day` into `dir` and registers it.
+///
+/// An existing module is only replaced if it is still exactly the template,
+/// so a solution, even a half finished one, is never lost.
+pub fn create(dir: &Path, day: usize) -> Result<PathBuf> {
+    PuzzleId::day(day)?;
+
+    let path = dir.join(format!("day{:02}.rs", day));
+    let source = render(day);
+
+    match read_to_string(&path) {
+        Ok(existing) if existing != source => {
+            bail!(
+                "Day {} already has a solution in {}, not overwriting it",
+                day,
+                path.display()
+            )
+        }
+        Ok(_) => {}
+        Err(e) if e.kind() == ErrorKind::NotFound => {}
+        Err(e) => return Err(e.into()),
+    }
+    write(&path, source)?;
+
+    let registry = dir.join("mod.rs");
+    write(&registry, register(&read_to_string(&registry)?, day)?)?;
+
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTRY: &str = "use crate::solution::Runnable;
+
+pub mod day01;
+pub mod day03;
+
+/// Every day of the calendar, in order.
+pub static DAYS: [&dyn Runnable; 2] = [
+    &day01::Day01,
+    &day03::Day03,
+];
+";
+
+    #[test]
+    fn test_render() {
+        let source = render(7);
+
+        assert!(source.contains("pub struct Day07;"));
+        assert!(source.contains("const DAY: usize = 7;"));
+        assert!(source.contains("examples::verify(&Day07, &[Part::Two])"));
+        assert!(source.contains("assert!(!checks.is_empty()"));
+        assert!(!source.contains("NN"));
+    }
+
+    #[test]
+    fn test_register() -> Result<()> {
+        assert_eq!(
+            register(REGISTRY, 2)?,
+            "use crate::solution::Runnable;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+
+/// Every day of the calendar, in order.
+pub static DAYS: [&dyn Runnable; 3] = [
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+];
+"
+        );
+        assert_eq!(register(REGISTRY, 3)?, REGISTRY);
+        assert!(register("", 1).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_create() -> Result<()> {
+        let dir = std::env::temp_dir().join(format!("advent-scaffold-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir)?;
+        write(dir.join("mod.rs"), REGISTRY)?;
+
+        let path = create(&dir, 2)?;
+        assert_eq!(read_to_string(&path)?, render(2));
+        assert!(read_to_string(dir.join("mod.rs"))?.contains("&day02::Day02,"));
+
+        // an untouched template can be generated again, a solution can't
+        assert!(create(&dir, 2).is_ok());
+        write(dir.join("day01.rs"), "// solved\n")?;
+        assert!(create(&dir, 1).is_err());
+        assert_eq!(read_to_string(dir.join("day01.rs"))?, "// solved\n");
+
+        assert!(create(&dir, 26).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_unsolved_days_match_template() -> Result<()> {
+        for day in 17..=25 {
+            let path = days_dir().join(format!("day{:02}.rs", day));
+            assert_eq!(read_to_string(path)?, render(day), "day {}", day);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,285 @@
+//! Breadth first search, Dijkstra and A* over any state that can list the
+//! states reachable from it.
+//!
+//! Every search stops as soon as `is_goal` accepts a state, so pass `|_| false`
+//! to explore everything reachable and get a complete distance map.
+
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+use num::Zero;
+
+/// What a search found: the best cost found so far to every state it
+/// reached, and how it got there.
+#[derive(Debug, Clone)]
+pub struct Search<S, C> {
+    distances: HashMap<S, C>,
+    parents: HashMap<S, S>,
+    goal: Option<S>,
+}
+
+impl<S: Eq + Hash + Clone, C: Copy> Search<S, C> {
+    fn new(start: S, zero: C) -> Self {
+        Self {
+            distances: HashMap::from([(start, zero)]),
+            parents: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    pub fn distances(&self) -> &HashMap<S, C> {
+        &self.distances
+    }
+
+    pub fn distance(&self, state: &S) -> Option<C> {
+        self.distances.get(state).copied()
+    }
+
+    /// The first state `is_goal` accepted, if any.
+    pub fn goal(&self) -> Option<&S> {
+        self.goal.as_ref()
+    }
+
+    /// The cost of reaching the goal.
+    pub fn cost(&self) -> Option<C> {
+        self.distance(self.goal.as_ref()?)
+    }
+
+    /// Every state from the start to `state`, both included.
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        self.distances.get(state)?;
+
+        let mut path = vec![state.clone()];
+        while let Some(parent) = self.parents.get(path.last()?) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    pub fn path(&self) -> Option<Vec<S>> {
+        self.path_to(self.goal.as_ref()?)
+    }
+}
+
+/// Searches in order of the number of steps taken, for when every step costs the same.
+pub fn bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, usize>
+where
+    S: Eq + Hash + Clone,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new(start.clone(), 0);
+    let mut frontier = VecDeque::from([start]);
+
+    while let Some(state) = frontier.pop_front() {
+        if is_goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+
+        let distance = search.distances[&state] + 1;
+        for next in successors(&state) {
+            if search.distances.contains_key(&next) {
+                continue;
+            }
+            search.distances.insert(next.clone(), distance);
+            search.parents.insert(next.clone(), state.clone());
+            frontier.push_back(next);
+        }
+    }
+
+    search
+}
+
+/// Searches in order of the total cost so far.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Eq + Hash + Clone,
+    C: Ord + Copy + Zero + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::zero(), is_goal)
+}
+
+/// Dijkstra, guided towards the goal by `heuristic`. The heuristic must never
+/// overestimate the remaining cost, or the path found may not be the cheapest.
+pub fn astar<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Eq + Hash + Clone,
+    C: Ord + Copy + Zero + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut search = Search::new(start.clone(), C::zero());
+    let mut frontier = BinaryHeap::from([Entry {
+        priority: heuristic(&start),
+        cost: C::zero(),
+        state: start,
+    }]);
+
+    while let Some(Entry { cost, state, .. }) = frontier.pop() {
+        // a cheaper way here was already handled
+        if search.distances[&state] < cost {
+            continue;
+        }
+        if is_goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+
+        for (next, step) in successors(&state) {
+            let cost = cost + step;
+            if search.distance(&next).is_some_and(|known| known <= cost) {
+                continue;
+            }
+            search.distances.insert(next.clone(), cost);
+            search.parents.insert(next.clone(), state.clone());
+            frontier.push(Entry {
+                priority: cost + heuristic(&next),
+                cost,
+                state: next,
+            });
+        }
+    }
+
+    search
+}
+
+// ordered by priority alone, lowest first, so states don't need to be `Ord`
+struct Entry<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> PartialEq for Entry<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Ord> Eq for Entry<S, C> {}
+
+impl<S, C: Ord> PartialOrd for Entry<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> Ord for Entry<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geom::{Direction4, Point2},
+        grid::Grid,
+    };
+
+    const MAZE: &str = "\
+S.#.....
+.##.###.
+....#...
+.####.#.
+......#E";
+
+    fn maze() -> Grid<char> {
+        MAZE.parse().unwrap()
+    }
+
+    fn open(grid: &Grid<char>, point: &Point2<usize>) -> Vec<Point2<usize>> {
+        Direction4::ALL
+            .into_iter()
+            .flat_map(|direction| point.step_within(direction, grid.bounds()))
+            .filter(|next| grid[*next] != '#')
+            .collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let grid = maze();
+        let end = Point2::new(7, 4);
+        let search = bfs(Point2::new(0, 0), |p| open(&grid, p), |p| *p == end);
+
+        assert_eq!(search.goal(), Some(&end));
+        assert_eq!(search.cost(), Some(15));
+
+        let path = search.path().unwrap();
+        assert_eq!(path.len(), 16);
+        assert_eq!(path.first(), Some(&Point2::new(0, 0)));
+        assert!(path.windows(2).all(|w| w[0].manhattan(&w[1]) == 1));
+    }
+
+    #[test]
+    fn test_bfs_distance_map() {
+        let grid = maze();
+        let search = bfs(Point2::new(0, 0), |p| open(&grid, p), |_| false);
+
+        assert_eq!(search.goal(), None);
+        assert_eq!(
+            search.distances().len(),
+            grid.iter().filter(|&&c| c != '#').count()
+        );
+        assert_eq!(search.distance(&Point2::new(3, 0)), Some(7));
+        assert_eq!(search.distance(&Point2::new(2, 0)), None);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        // going straight through costs more than going round
+        let edges = HashMap::from([
+            ('a', vec![('b', 7), ('c', 1)]),
+            ('c', vec![('d', 2)]),
+            ('d', vec![('b', 3)]),
+            ('b', vec![('e', 1)]),
+        ]);
+        let successors = |s: &char| edges.get(s).cloned().unwrap_or_default();
+
+        let search = dijkstra('a', successors, |&s| s == 'e');
+        assert_eq!(search.cost(), Some(7));
+        assert_eq!(search.path(), Some(vec!['a', 'c', 'd', 'b', 'e']));
+
+        let search = dijkstra('a', successors, |_| false);
+        assert_eq!(search.distance(&'b'), Some(6));
+        assert_eq!(search.path_to(&'z'), None);
+    }
+
+    #[test]
+    fn test_astar_agrees_with_bfs() {
+        let grid = maze();
+        let end = Point2::new(7, 4);
+        let weighted = |p: &Point2<usize>| open(&grid, p).into_iter().map(|next| (next, 1));
+
+        let guided = astar(
+            Point2::new(0, 0),
+            weighted,
+            |p| p.manhattan(&end),
+            |p| *p == end,
+        );
+        let blind = bfs(Point2::new(0, 0), |p| open(&grid, p), |p| *p == end);
+
+        assert_eq!(guided.cost(), blind.cost());
+        // and it shouldn't have needed to look at any more states to do it
+        assert!(guided.distances().len() <= blind.distances().len());
+    }
+}
@@ -0,0 +1,188 @@
+use std::{fmt::Display, str::FromStr};
+
+use anyhow::{bail, Error, Result};
+
+use crate::bench::{measure, Config, Stage, Timing};
+
+/// Which half of a day's puzzle to solve.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn values() -> [Self; 2] {
+        [Self::One, Self::Two]
+    }
+
+    pub fn number(&self) -> usize {
+        match self {
+            Self::One => 1,
+            Self::Two => 2,
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "1" => Ok(Self::One),
+            "2" => Ok(Self::Two),
+            _ => bail!("Invalid part: {} (expected 1 or 2)", s),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// A single day of the calendar.
+///
+/// The input is parsed once and then shared by both parts, so anything
+/// expensive to build (grids, maps, ...) belongs in `Input`.
+pub trait Solution {
+    const DAY: usize;
+
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(&self, input: &str) -> Result<Self::Input>;
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1>;
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2>;
+}
+
+/// Object safe view of a [`Solution`] with its answers rendered as strings,
+/// so every day can live in the same registry.
+pub trait Runnable: Sync {
+    fn day(&self) -> usize;
+    fn run(&self, input: &str, parts: &[Part]) -> Result<Vec<String>>;
+
+    /// Parses `input` once, returning something that solves any part of it,
+    /// so each part can be run and timed on its own.
+    fn prepare<'a>(&'a self, input: &str) -> Result<Box<dyn Fn(Part) -> Result<String> + 'a>>;
+
+    /// Times parsing and each of `parts` separately.
+    fn bench(&self, input: &str, parts: &[Part], config: &Config) -> Result<Vec<Timing>>;
+}
+
+impl<S> Runnable for S
+where
+    S: Solution + Sync,
+    S::Input: 'static,
+{
+    fn day(&self) -> usize {
+        S::DAY
+    }
+
+    fn run(&self, input: &str, parts: &[Part]) -> Result<Vec<String>> {
+        let solve = self.prepare(input)?;
+        parts.iter().map(|&part| solve(part)).collect()
+    }
+
+    fn prepare<'a>(&'a self, input: &str) -> Result<Box<dyn Fn(Part) -> Result<String> + 'a>> {
+        let parsed = self.parse(input)?;
+
+        Ok(Box::new(move |part| match part {
+            Part::One => Ok(self.part1(&parsed)?.to_string()),
+            Part::Two => Ok(self.part2(&parsed)?.to_string()),
+        }))
+    }
+
+    fn bench(&self, input: &str, parts: &[Part], config: &Config) -> Result<Vec<Timing>> {
+        let timing = |stage, stats| Timing {
+            day: S::DAY,
+            stage,
+            stats,
+        };
+
+        let mut timings = vec![timing(Stage::Parse, measure(config, || self.parse(input))?)];
+
+        let parsed = self.parse(input)?;
+        for &part in parts {
+            let stats = match part {
+                Part::One => measure(config, || self.part1(&parsed))?,
+                Part::Two => measure(config, || self.part2(&parsed))?,
+            };
+            timings.push(timing(Stage::Part(part), stats));
+        }
+
+        Ok(timings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Doubler;
+
+    impl Solution for Doubler {
+        const DAY: usize = 1;
+
+        type Input = Vec<i32>;
+        type Part1 = i32;
+        type Part2 = String;
+
+        fn parse(&self, input: &str) -> Result<Self::Input> {
+            Ok(input
+                .split_ascii_whitespace()
+                .map(|s| s.parse::<i32>())
+                .collect::<Result<Vec<_>, _>>()?)
+        }
+
+        fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+            Ok(input.iter().map(|n| n * 2).sum())
+        }
+
+        fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+            Ok(format!("{:?}", input))
+        }
+    }
+
+    #[test]
+    fn test_part_from_str() -> Result<()> {
+        assert_eq!("1".parse::<Part>()?, Part::One);
+        assert_eq!("2".parse::<Part>()?, Part::Two);
+        assert!("3".parse::<Part>().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_run() -> Result<()> {
+        let runnable: &dyn Runnable = &Doubler;
+
+        assert_eq!(runnable.day(), 1);
+        assert_eq!(
+            runnable.run("1 2 3", &Part::values())?,
+            vec!["12".to_string(), "[1, 2, 3]".to_string()]
+        );
+        assert_eq!(runnable.run("1 2 3", &[Part::Two])?, vec!["[1, 2, 3]"]);
+        assert!(runnable.run("1 two 3", &[Part::One]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_bench() -> Result<()> {
+        let runnable: &dyn Runnable = &Doubler;
+        let config = Config { warmup: 1, runs: 3 };
+
+        let stages = runnable
+            .bench("1 2 3", &[Part::Two], &config)?
+            .into_iter()
+            .map(|t| (t.day, t.stage, t.stats.runs))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            stages,
+            vec![(1, Stage::Parse, 3), (1, Stage::Part(Part::Two), 3)]
+        );
+        assert!(runnable.bench("1 two 3", &[], &config).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,355 @@
+use std::{
+    fmt::Display,
+    fs::{create_dir_all, read_to_string, OpenOptions},
+    io::{ErrorKind, Write},
+    path::PathBuf,
+    str::FromStr,
+    time::Duration,
+};
+
+use anyhow::{anyhow, bail, Error, Result};
+use regex::Regex;
+
+use crate::{client::Client, io, puzzle::PuzzleId, solution::Part};
+
+/// What the server thought of a submitted answer.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    Wait(Duration),
+    AlreadySolved,
+}
+
+impl Verdict {
+    /// Reads the verdict out of the page returned after posting an answer.
+    pub fn from_response(html: &str) -> Result<Self> {
+        let article = Regex::new(r"(?s)<article>(.*?)</article>")?
+            .captures(html)
+            .and_then(|c| c.get(1))
+            .map(|m| m.as_str())
+            .unwrap_or(html);
+
+        if article.contains("That's the right answer") {
+            Ok(Self::Correct)
+        } else if article.contains("That's not the right answer") {
+            if article.contains("too high") {
+                Ok(Self::TooHigh)
+            } else if article.contains("too low") {
+                Ok(Self::TooLow)
+            } else {
+                Ok(Self::Incorrect)
+            }
+        } else if article.contains("You gave an answer too recently") {
+            let left = Regex::new(r"You have (.*?) left to wait")?
+                .captures(article)
+                .and_then(|c| c.get(1))
+                .ok_or(anyhow!(
+                    "Could not find the time left to wait in {}",
+                    article
+                ))?;
+
+            Ok(Self::Wait(parse_wait(left.as_str())?))
+        } else if article.contains("You don't seem to be solving the right level") {
+            Ok(Self::AlreadySolved)
+        } else {
+            bail!("Unrecognised response: {}", article.trim())
+        }
+    }
+
+    fn is_final(&self) -> bool {
+        !matches!(self, Self::Wait(_) | Self::AlreadySolved)
+    }
+}
+
+// "1m 5s", "35s"
+fn parse_wait(s: &str) -> Result<Duration> {
+    Regex::new(r"(\d+)([hms])")?
+        .captures_iter(s)
+        .map(|c| {
+            let n = c[1].parse::<u64>()?;
+            Ok(match &c[2] {
+                "h" => n * 60 * 60,
+                "m" => n * 60,
+                _ => n,
+            })
+        })
+        .sum::<Result<u64>>()
+        .map(Duration::from_secs)
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Correct => write!(f, "correct"),
+            Self::TooHigh => write!(f, "too high"),
+            Self::TooLow => write!(f, "too low"),
+            Self::Incorrect => write!(f, "incorrect"),
+            Self::Wait(d) => write!(f, "wait {}s", d.as_secs()),
+            Self::AlreadySolved => write!(f, "already solved"),
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "correct" => Ok(Self::Correct),
+            "too high" => Ok(Self::TooHigh),
+            "too low" => Ok(Self::TooLow),
+            "incorrect" => Ok(Self::Incorrect),
+            "already solved" => Ok(Self::AlreadySolved),
+            _ => {
+                let wait = s
+                    .strip_prefix("wait ")
+                    .ok_or(anyhow!("Invalid verdict: {}", s))?;
+                Ok(Self::Wait(parse_wait(wait)?))
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Attempt {
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+impl Display for Attempt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}\t{}\t{}", self.part, self.answer, self.verdict)
+    }
+}
+
+impl FromStr for Attempt {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let parts = s.split('\t').collect::<Vec<_>>();
+        let [part, answer, verdict] = parts.as_slice() else {
+            bail!("Invalid attempt: {}", s);
+        };
+
+        Ok(Self {
+            part: part.parse()?,
+            answer: answer.to_string(),
+            verdict: verdict.parse()?,
+        })
+    }
+}
+
+/// Every answer submitted for a day, one tab separated attempt per line.
+pub struct Log {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl Log {
+    /// Submissions are kept next to the input they were computed from.
+    pub fn path_for(puzzle: PuzzleId) -> PathBuf {
+        io::input_path(puzzle).with_file_name("submissions")
+    }
+
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+
+        let attempts = match read_to_string(&path) {
+            Ok(s) => s
+                .lines()
+                .map(Attempt::from_str)
+                .collect::<Result<Vec<_>>>()?,
+            Err(e) if e.kind() == ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(e.into()),
+        };
+
+        Ok(Self { path, attempts })
+    }
+
+    pub fn attempts(&self) -> &[Attempt] {
+        &self.attempts
+    }
+
+    /// The verdict we can already predict for `answer` from earlier attempts.
+    pub fn check(&self, part: Part, answer: &str) -> Option<Verdict> {
+        let attempts = self
+            .attempts
+            .iter()
+            .filter(|a| a.part == part && a.verdict.is_final())
+            .collect::<Vec<_>>();
+
+        if let Some(a) = attempts.iter().find(|a| a.answer == answer) {
+            return Some(a.verdict);
+        }
+
+        if attempts.iter().any(|a| a.verdict == Verdict::Correct) {
+            return Some(Verdict::Incorrect);
+        }
+
+        let answer = answer.parse::<i128>().ok()?;
+        attempts.iter().find_map(|a| {
+            let previous = a.answer.parse::<i128>().ok()?;
+            match a.verdict {
+                Verdict::TooHigh if answer >= previous => Some(Verdict::TooHigh),
+                Verdict::TooLow if answer <= previous => Some(Verdict::TooLow),
+                _ => None,
+            }
+        })
+    }
+
+    pub fn record(&mut self, attempt: Attempt) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            create_dir_all(dir)?;
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", attempt)?;
+
+        self.attempts.push(attempt);
+        Ok(())
+    }
+}
+
+/// Posts `answer` unless the log already tells us how it would be judged.
+pub fn submit(
+    client: &Client,
+    log: &mut Log,
+    puzzle: PuzzleId,
+    part: Part,
+    answer: &str,
+) -> Result<Verdict> {
+    if let Some(verdict) = log.check(part, answer) {
+        bail!(
+            "Not submitting {} for {} part {}, it is already known to be {}",
+            answer,
+            puzzle,
+            part,
+            verdict
+        );
+    }
+
+    let verdict = Verdict::from_response(&client.answer(puzzle, part, answer)?)?;
+    log.record(Attempt {
+        part,
+        answer: answer.to_string(),
+        verdict,
+    })?;
+
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_server::MockServer;
+
+    const CORRECT: &str = r#"<main><article><p>That's the right answer!  You are <span class="day-success">one gold star</span> closer to restoring snow operations. [<a href="/2023/day/7#part2">Continue to Part Two</a>]</p></article></main>"#;
+    const TOO_HIGH: &str = r#"<main><article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href="/2023/about">about page</a>, or you can ask for hints on the <a href="https://www.reddit.com/r/adventofcode/" target="_blank">subreddit</a>.  Please wait one minute before trying again. [<a href="/2023/day/7">Return to Day 7</a>]</p></article></main>"#;
+    const TOO_LOW: &str = "<article><p>That's not the right answer; your answer is too low.  Please wait one minute before trying again.</p></article>";
+    const INCORRECT: &str = "<article><p>That's not the right answer.  If you're stuck, make sure you're using the full input data.  Please wait one minute before trying again.</p></article>";
+    const WAIT: &str = r#"<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait. [<a href="/2023/day/7">Return to Day 7</a>]</p></article>"#;
+    const SOLVED: &str = r#"<article><p>You don't seem to be solving the right level.  Did you already complete it? [<a href="/2023/day/7">Return to Day 7</a>]</p></article>"#;
+
+    fn temp_log(name: &str) -> Result<Log> {
+        let path = std::env::temp_dir().join(format!(
+            "advent-submit-{}-{}/submissions",
+            name,
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+        Log::open(path)
+    }
+
+    #[test]
+    fn test_from_response() -> Result<()> {
+        assert_eq!(Verdict::from_response(CORRECT)?, Verdict::Correct);
+        assert_eq!(Verdict::from_response(TOO_HIGH)?, Verdict::TooHigh);
+        assert_eq!(Verdict::from_response(TOO_LOW)?, Verdict::TooLow);
+        assert_eq!(Verdict::from_response(INCORRECT)?, Verdict::Incorrect);
+        assert_eq!(
+            Verdict::from_response(WAIT)?,
+            Verdict::Wait(Duration::from_secs(65))
+        );
+        assert_eq!(Verdict::from_response(SOLVED)?, Verdict::AlreadySolved);
+        assert!(Verdict::from_response("<article><p>?</p></article>").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_verdict_round_trip() -> Result<()> {
+        for verdict in [
+            Verdict::Correct,
+            Verdict::TooHigh,
+            Verdict::TooLow,
+            Verdict::Incorrect,
+            Verdict::Wait(Duration::from_secs(35)),
+            Verdict::AlreadySolved,
+        ] {
+            assert_eq!(verdict.to_string().parse::<Verdict>()?, verdict);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_check() -> Result<()> {
+        let mut log = temp_log("check")?;
+        let attempt = |part, answer: &str, verdict| Attempt {
+            part,
+            answer: answer.to_string(),
+            verdict,
+        };
+
+        log.record(attempt(Part::One, "100", Verdict::TooHigh))?;
+        log.record(attempt(Part::One, "10", Verdict::TooLow))?;
+        log.record(attempt(
+            Part::One,
+            "50",
+            Verdict::Wait(Duration::from_secs(30)),
+        ))?;
+        log.record(attempt(Part::One, "abc", Verdict::Incorrect))?;
+        log.record(attempt(Part::Two, "42", Verdict::Correct))?;
+
+        assert_eq!(log.check(Part::One, "100"), Some(Verdict::TooHigh));
+        assert_eq!(log.check(Part::One, "250"), Some(Verdict::TooHigh));
+        assert_eq!(log.check(Part::One, "3"), Some(Verdict::TooLow));
+        assert_eq!(log.check(Part::One, "abc"), Some(Verdict::Incorrect));
+        assert_eq!(log.check(Part::One, "50"), None);
+        assert_eq!(log.check(Part::One, "xyz"), None);
+        assert_eq!(log.check(Part::Two, "42"), Some(Verdict::Correct));
+        assert_eq!(log.check(Part::Two, "43"), Some(Verdict::Incorrect));
+
+        // everything survives a reload
+        let reloaded = Log::open(&log.path)?;
+        assert_eq!(reloaded.attempts(), log.attempts());
+        Ok(())
+    }
+
+    #[test]
+    fn test_submit() -> Result<()> {
+        let server = MockServer::start(vec![(200, TOO_HIGH), (200, CORRECT)]);
+        let client = Client::new(&server.base_url, "abc123").with_min_interval(Duration::ZERO);
+        let mut log = temp_log("submit")?;
+        let day = PuzzleId::day(7)?;
+
+        assert_eq!(
+            submit(&client, &mut log, day, Part::One, "6500")?,
+            Verdict::TooHigh
+        );
+        assert!(submit(&client, &mut log, day, Part::One, "6600").is_err());
+        assert_eq!(
+            submit(&client, &mut log, day, Part::One, "6440")?,
+            Verdict::Correct
+        );
+
+        assert_eq!(server.requests().len(), 2);
+        assert_eq!(log.attempts().len(), 2);
+        Ok(())
+    }
+}
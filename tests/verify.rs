@@ -0,0 +1,18 @@
+use advent::{answers, days};
+
+#[test]
+fn test_known_answers() {
+    let mut failures = Vec::new();
+
+    for day in days::DAYS {
+        let checks = answers::verify(day).unwrap();
+        for check in checks.into_iter().filter(|c| !c.passed()) {
+            failures.push(format!(
+                "day {} part {}: expected {}, got {}",
+                check.day, check.part, check.expected, check.actual
+            ));
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}